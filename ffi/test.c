@@ -0,0 +1,120 @@
+/* Exercises the C API: build the library first with `cargo build --release`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "typogenetics.h"
+
+static int n_failed = 0;
+
+static void check(int condition, const char *what) {
+  if (!condition) {
+    fprintf(stderr, "FAIL %s\n", what);
+    n_failed++;
+  }
+}
+
+static void check_string(char *actual, const char *expected, const char *what) {
+  check(actual != NULL && strcmp(actual, expected) == 0, what);
+  tg_string_free(actual);
+}
+
+static void test_strands(void) {
+  TgStrand *strand = NULL;
+  check(tg_strand_new("ACGT", &strand) == TG_STATUS_OK, "strand parses");
+  check(tg_strand_len(strand) == 4, "strand length");
+  check_string(tg_strand_to_string(strand), "ACGT", "strand to string");
+  tg_strand_free(strand);
+
+  TgStrand *invalid = NULL;
+  check(tg_strand_new("ACXT", &invalid) == TG_STATUS_INVALID_STRAND, "invalid strand fails");
+  check(invalid == NULL, "failed parse leaves out untouched");
+  check(tg_strand_new(NULL, &invalid) == TG_STATUS_NULL_POINTER, "null strand fails");
+  check(strcmp(tg_status_message(TG_STATUS_INVALID_STRAND), "ok") != 0, "status message");
+}
+
+static void test_translate(void) {
+  TgStrand *strand = NULL;
+  TgEnzymeList *enzymes = NULL;
+  tg_strand_new("ACAACA", &strand);
+  check(tg_translate(strand, &enzymes) == TG_STATUS_OK, "translate");
+  check(tg_enzyme_list_len(enzymes) == 2, "translation has two enzymes");
+
+  const TgEnzyme *enzyme = NULL;
+  check(tg_enzyme_list_get(enzymes, 1, &enzyme) == TG_STATUS_OK, "get enzyme");
+  check_string(tg_enzyme_to_string(enzyme), "mvr", "second enzyme");
+  check(tg_enzyme_list_get(enzymes, 2, &enzyme) == TG_STATUS_INDEX_OUT_OF_RANGE,
+        "enzyme index out of range");
+
+  tg_enzyme_list_free(enzymes);
+  tg_strand_free(strand);
+}
+
+static void test_rewrite_and_fold(void) {
+  TgEnzyme *enzyme = NULL;
+  TgStrand *strand = NULL;
+  TgStrandList *products = NULL;
+  check(tg_enzyme_new("cut", &enzyme) == TG_STATUS_OK, "enzyme parses");
+  check(tg_enzyme_new("cut-foo", &(TgEnzyme *){NULL}) == TG_STATUS_INVALID_ENZYME,
+        "invalid enzyme fails");
+
+  TgFold fold;
+  check(tg_enzyme_fold(enzyme, &fold) == TG_STATUS_OK, "fold");
+  check(fold.orientation == 'R' && fold.binding_base == 'A', "cut binds A");
+
+  tg_strand_new("CAGT", &strand);
+  check(tg_rewrite(enzyme, strand, &products) == TG_STATUS_OK, "rewrite");
+  check(tg_strand_list_len(products) == 2, "cut gives two products");
+  const TgStrand *product = NULL;
+  tg_strand_list_get(products, 0, &product);
+  check_string(tg_strand_to_string(product), "GT", "first product");
+  tg_strand_list_get(products, 1, &product);
+  check_string(tg_strand_to_string(product), "CA", "second product");
+
+  tg_strand_list_free(products);
+  tg_strand_free(strand);
+  tg_enzyme_free(enzyme);
+}
+
+static void test_simulation(void) {
+  TgStrand *strand = NULL;
+  TgSimulation *simulation = NULL;
+  TgStrandList *first = NULL;
+  TgStrandList *second = NULL;
+  TgRunStats stats;
+  tg_strand_new("ATAGCGAATAGGATAATG", &strand);
+  check(tg_simulation_new(strand, &simulation) == TG_STATUS_OK, "simulation");
+  tg_strand_free(strand);
+  tg_simulation_set_iterations(simulation, 1000);
+  tg_simulation_set_seed(simulation, 42);
+  tg_simulation_set_max_strand_len(simulation, 30);
+
+  check(tg_simulation_run(simulation, &first, &stats) == TG_STATUS_OK, "run");
+  check(stats.n_iterations == 1000, "ran every iteration");
+  check(tg_strand_list_len(first) > 1, "discovered strands");
+  for (size_t i = 0; i < tg_strand_list_len(first); i++) {
+    const TgStrand *found = NULL;
+    tg_strand_list_get(first, i, &found);
+    check(tg_strand_len(found) <= 30, "strand length limit");
+  }
+
+  check(tg_simulation_run(simulation, &second, NULL) == TG_STATUS_OK, "second run");
+  check(tg_strand_list_len(first) == tg_strand_list_len(second), "runs are reproducible");
+
+  tg_strand_list_free(first);
+  tg_strand_list_free(second);
+  tg_simulation_free(simulation);
+}
+
+int main(void) {
+  test_strands();
+  test_translate();
+  test_rewrite_and_fold();
+  test_simulation();
+  if (n_failed > 0) {
+    fprintf(stderr, "%d checks failed\n", n_failed);
+    return 1;
+  }
+  printf("All checks passed\n");
+  return 0;
+}
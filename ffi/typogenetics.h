@@ -0,0 +1,257 @@
+#ifndef TYPOGENETICS_H
+#define TYPOGENETICS_H
+
+/* Generated by cbindgen from src/ffi, do not edit. Regenerate with: cbindgen --output ffi/typogenetics.h */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of a call into the C API.
+typedef enum TgStatus {
+  TG_STATUS_OK = 0,
+  TG_STATUS_NULL_POINTER = 1,
+  TG_STATUS_INVALID_UTF8 = 2,
+  TG_STATUS_INVALID_STRAND = 3,
+  TG_STATUS_INVALID_ENZYME = 4,
+  TG_STATUS_INDEX_OUT_OF_RANGE = 5,
+  TG_STATUS_INTERNAL_ERROR = 6,
+} TgStatus;
+
+// Opaque handle to an enzyme.
+typedef struct TgEnzyme TgEnzyme;
+
+// Opaque handle to a list of enzymes returned by the library.
+typedef struct TgEnzymeList TgEnzymeList;
+
+// Opaque handle to the settings of a random simulation, see `Simulation`. A handle can
+// be run any number of times.
+typedef struct TgSimulation TgSimulation;
+
+// Opaque handle to a strand.
+typedef struct TgStrand TgStrand;
+
+// Opaque handle to a list of strands returned by the library.
+typedef struct TgStrandList TgStrandList;
+
+// How an enzyme folds: its final orientation, one of 'U', 'D', 'L' or 'R', and the
+// base it binds to, one of 'A', 'C', 'G' or 'T'.
+typedef struct TgFold {
+  char orientation;
+  char binding_base;
+} TgFold;
+
+// Statistics of a finished simulation run.
+typedef struct TgRunStats {
+  size_t n_iterations;
+  size_t n_rewrites;
+  double elapsed_seconds;
+} TgRunStats;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parse an enzyme of amino acids separated by '-', such as "cop-mvr-cut".
+//
+// # Safety
+//
+// `amino_acids` must be null or a NUL terminated string, and `out` must be null or
+// valid for writes.
+enum TgStatus tg_enzyme_new(const char *amino_acids, struct TgEnzyme **out);
+
+// The amino acids of the enzyme, to be released with `tg_string_free`, or null for
+// null.
+//
+// # Safety
+//
+// `enzyme` must be null or a live enzyme handle.
+char *tg_enzyme_to_string(const struct TgEnzyme *enzyme);
+
+// Fold the enzyme, writing its final orientation and the base it binds to.
+//
+// # Safety
+//
+// `enzyme` must be null or a live enzyme handle, and `out` must be null or valid for
+// writes.
+enum TgStatus tg_enzyme_fold(const struct TgEnzyme *enzyme, struct TgFold *out);
+
+// Rewrite a strand with the enzyme, writing the products to a new list.
+//
+// # Safety
+//
+// `enzyme` and `strand` must be null or live handles, and `out` must be null or valid
+// for writes.
+enum TgStatus tg_rewrite(const struct TgEnzyme *enzyme,
+                         const struct TgStrand *strand,
+                         struct TgStrandList **out);
+
+// Release an enzyme. Does nothing for null.
+//
+// # Safety
+//
+// `enzyme` must be null or an enzyme handle that has not been freed. Enzymes borrowed
+// from a list must not be freed.
+void tg_enzyme_free(struct TgEnzyme *enzyme);
+
+// Number of enzymes in the list, or 0 for null.
+//
+// # Safety
+//
+// `list` must be null or a live list handle.
+size_t tg_enzyme_list_len(const struct TgEnzymeList *list);
+
+// Borrow the enzyme at an index. The enzyme lives as long as the list and must not be
+// freed on its own.
+//
+// # Safety
+//
+// `list` must be null or a live list handle, and `out` must be null or valid for
+// writes.
+enum TgStatus tg_enzyme_list_get(const struct TgEnzymeList *list,
+                                 size_t index,
+                                 const struct TgEnzyme **out);
+
+// Release a list and the enzymes in it. Does nothing for null.
+//
+// # Safety
+//
+// `list` must be null or a list handle that has not been freed.
+void tg_enzyme_list_free(struct TgEnzymeList *list);
+
+// Create a simulation starting from a strand, with the defaults of `Simulation`.
+//
+// # Safety
+//
+// `init_strand` must be null or a live strand handle, and `out` must be null or valid
+// for writes.
+enum TgStatus tg_simulation_new(const struct TgStrand *init_strand, struct TgSimulation **out);
+
+// Set the number of iterations to run.
+//
+// # Safety
+//
+// `simulation` must be null or a live simulation handle.
+enum TgStatus tg_simulation_set_iterations(struct TgSimulation *simulation, size_t n_iterations);
+
+// Set the seed of the random number generator.
+//
+// # Safety
+//
+// `simulation` must be null or a live simulation handle.
+enum TgStatus tg_simulation_set_seed(struct TgSimulation *simulation, uint64_t seed);
+
+// Drop products longer than this many bases, or 0 for no limit.
+//
+// # Safety
+//
+// `simulation` must be null or a live simulation handle.
+enum TgStatus tg_simulation_set_max_strand_len(struct TgSimulation *simulation,
+                                               size_t max_strand_len);
+
+// Drop new strands once the soup holds this many, or 0 for no limit.
+//
+// # Safety
+//
+// `simulation` must be null or a live simulation handle.
+enum TgStatus tg_simulation_set_max_population(struct TgSimulation *simulation,
+                                               size_t max_population);
+
+// Run the simulation, writing the discovered strands in the order they were found to
+// a new list and the run statistics to `stats`, which may be null.
+//
+// # Safety
+//
+// `simulation` must be null or a live simulation handle, `out` must be null or valid
+// for writes, and `stats` must be null or valid for writes.
+enum TgStatus tg_simulation_run(const struct TgSimulation *simulation,
+                                struct TgStrandList **out,
+                                struct TgRunStats *stats);
+
+// Release a simulation. Does nothing for null.
+//
+// # Safety
+//
+// `simulation` must be null or a simulation handle that has not been freed.
+void tg_simulation_free(struct TgSimulation *simulation);
+
+// A static description of a status, which must not be freed.
+const char *tg_status_message(enum TgStatus status);
+
+// Parse a strand of bases A, C, G and T.
+//
+// # Safety
+//
+// `bases` must be null or a NUL terminated string, and `out` must be null or valid
+// for writes.
+enum TgStatus tg_strand_new(const char *bases, struct TgStrand **out);
+
+// Number of bases in the strand, or 0 for null.
+//
+// # Safety
+//
+// `strand` must be null or a live strand handle.
+size_t tg_strand_len(const struct TgStrand *strand);
+
+// The bases of the strand, to be released with `tg_string_free`, or null for null.
+//
+// # Safety
+//
+// `strand` must be null or a live strand handle.
+char *tg_strand_to_string(const struct TgStrand *strand);
+
+// Release a strand. Does nothing for null.
+//
+// # Safety
+//
+// `strand` must be null or a strand handle that has not been freed. Strands borrowed
+// from a list must not be freed.
+void tg_strand_free(struct TgStrand *strand);
+
+// Translate the strand, writing its enzymes to a new list.
+//
+// # Safety
+//
+// `strand` must be null or a live strand handle, and `out` must be null or valid for
+// writes.
+enum TgStatus tg_translate(const struct TgStrand *strand, struct TgEnzymeList **out);
+
+// Number of strands in the list, or 0 for null.
+//
+// # Safety
+//
+// `list` must be null or a live list handle.
+size_t tg_strand_list_len(const struct TgStrandList *list);
+
+// Borrow the strand at an index. The strand lives as long as the list and must not be
+// freed on its own.
+//
+// # Safety
+//
+// `list` must be null or a live list handle, and `out` must be null or valid for
+// writes.
+enum TgStatus tg_strand_list_get(const struct TgStrandList *list,
+                                 size_t index,
+                                 const struct TgStrand **out);
+
+// Release a list and the strands in it. Does nothing for null.
+//
+// # Safety
+//
+// `list` must be null or a list handle that has not been freed.
+void tg_strand_list_free(struct TgStrandList *list);
+
+// Release a string returned by the library. Does nothing for null.
+//
+// # Safety
+//
+// `s` must be null or a string returned by the library that has not been freed.
+void tg_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TYPOGENETICS_H */
@@ -0,0 +1,11 @@
+use crate::analysis::Reaction;
+use crate::typogenetics::Strand;
+
+/// A reflexively autocatalytic and food-generated (RAF) set of reactions. Every reaction
+/// is catalysed by a strand in `strands` and rewrites a strand in `strands`, and every
+/// strand is either food or produced by one of the reactions.
+#[derive(Debug, Clone)]
+pub struct AutocatalyticSet {
+    pub reactions: Vec<Reaction>,
+    pub strands: Vec<Strand>,
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::analysis::SymbolicBase;
+use crate::typogenetics::{Base, BaseType};
+
+/// A constraint on the unknown strand that must hold for a symbolic path to be taken.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Condition {
+    IsType {
+        offset: i32,
+        base_type: BaseType,
+    },
+    /// Bases left of the binding site differ from the binding base, since an enzyme
+    /// binds to the first occurrence of its base.
+    IsNot {
+        offset: i32,
+        base: Base,
+    },
+    Extends {
+        offset: i32,
+    },
+    EndsBefore {
+        offset: i32,
+    },
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Condition::IsType { offset, base_type } => {
+                let type_str = match base_type {
+                    BaseType::Purine => "purine",
+                    BaseType::Pyrimidine => "pyrimidine",
+                };
+                write!(f, "{} is a {}", SymbolicBase::original(*offset), type_str)
+            }
+            Condition::IsNot { offset, base } => {
+                write!(f, "{} is not {}", SymbolicBase::original(*offset), base)
+            }
+            Condition::Extends { offset } => {
+                write!(f, "strand extends to {}", SymbolicBase::original(*offset))
+            }
+            Condition::EndsBefore { offset } => {
+                write!(f, "strand ends before {}", SymbolicBase::original(*offset))
+            }
+        }
+    }
+}
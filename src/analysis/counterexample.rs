@@ -0,0 +1,9 @@
+use crate::typogenetics::Strand;
+
+/// A strand on which two enzymes produce different products.
+#[derive(Debug, Clone)]
+pub struct Counterexample {
+    pub strand: Strand,
+    pub products_a: Vec<Strand>,
+    pub products_b: Vec<Strand>,
+}
@@ -0,0 +1,133 @@
+use std::thread;
+
+use crate::analysis::Counterexample;
+use crate::typogenetics::{Base, Enzyme, Rewriter, Strand};
+
+/// Decides whether two enzymes behave identically by rewriting every strand up to a
+/// maximum length with both and comparing the resulting multisets of products.
+pub struct EquivalenceChecker;
+
+impl EquivalenceChecker {
+    const BASES: [Base; 4] = [Base::A, Base::C, Base::G, Base::T];
+
+    /// Longest strands that can be numbered, since there are 4^len strands of a length.
+    pub const MAX_LEN: usize = 31;
+
+    /// Returns the smallest strand on which the enzymes differ, ordering strands by
+    /// length and then alphabetically, or `None` if no such strand exists. Fails if
+    /// `max_len` exceeds `MAX_LEN`.
+    pub fn check(
+        enzyme_a: &Enzyme,
+        enzyme_b: &Enzyme,
+        max_len: usize,
+        n_threads: usize,
+    ) -> Result<Option<Counterexample>, String> {
+        if max_len > Self::MAX_LEN {
+            return Err(format!(
+                "Invalid maximum length {}, expected at most {}",
+                max_len,
+                Self::MAX_LEN
+            ));
+        }
+        let n_threads = n_threads.max(1);
+
+        for len in 1..=max_len {
+            let n_strands = 4_u64.pow(len as u32);
+            let chunk_size = n_strands.div_ceil(n_threads as u64);
+
+            let found = thread::scope(|scope| {
+                let handles: Vec<_> = (0..n_threads as u64)
+                    .map(|t| {
+                        let start = (t * chunk_size).min(n_strands);
+                        let end = ((t + 1) * chunk_size).min(n_strands);
+                        scope.spawn(move || {
+                            (start..end).find_map(|index| {
+                                let strand = Self::strand_from_index(index, len);
+                                Self::compare(enzyme_a, enzyme_b, &strand)
+                            })
+                        })
+                    })
+                    .collect();
+
+                // Chunks are in ascending order, so the first hit is the smallest
+                handles
+                    .into_iter()
+                    .map(|h| h.join().expect("Equivalence worker panicked"))
+                    .find(|c| c.is_some())
+                    .flatten()
+            });
+
+            if found.is_some() {
+                return Ok(found);
+            }
+            log::debug!(
+                "Enzymes agree on all {} strands of length {}",
+                n_strands,
+                len
+            );
+        }
+
+        Ok(None)
+    }
+
+    /// Returns a counterexample if the enzymes produce different products on a strand.
+    pub fn compare(
+        enzyme_a: &Enzyme,
+        enzyme_b: &Enzyme,
+        strand: &Strand,
+    ) -> Option<Counterexample> {
+        let products_a = Rewriter::rewrite(enzyme_a, strand);
+        let products_b = Rewriter::rewrite(enzyme_b, strand);
+        if Self::to_multiset(&products_a) == Self::to_multiset(&products_b) {
+            None
+        } else {
+            Some(Counterexample {
+                strand: strand.clone(),
+                products_a,
+                products_b,
+            })
+        }
+    }
+
+    fn to_multiset(strands: &[Strand]) -> Vec<String> {
+        let mut multiset: Vec<String> = strands.iter().map(|s| s.to_string()).collect();
+        multiset.sort();
+        multiset
+    }
+
+    fn strand_from_index(mut index: u64, len: usize) -> Strand {
+        let mut bases = vec![Base::A; len];
+        for pos in (0..len).rev() {
+            bases[pos] = Self::BASES[(index % 4) as usize];
+            index /= 4;
+        }
+        Strand::new(bases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_lengths_that_cannot_be_numbered() {
+        let enzyme = Enzyme::from_string("cut");
+        let result = EquivalenceChecker::check(&enzyme, &enzyme, 32, 1);
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid maximum length 32, expected at most 31"
+        );
+    }
+
+    #[test]
+    fn finds_smallest_counterexample() {
+        let cut = Enzyme::from_string("cut");
+        let del = Enzyme::from_string("del");
+        let counterexample = EquivalenceChecker::check(&cut, &del, 3, 2)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(counterexample.strand, Strand::from_string("A"));
+    }
+}
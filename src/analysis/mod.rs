@@ -0,0 +1,37 @@
+mod autocatalytic_set;
+mod condition;
+mod counterexample;
+mod equivalence_checker;
+mod outcome;
+mod pathway;
+mod pathway_finder;
+mod raf_finder;
+mod reaction;
+mod replication_class;
+mod self_application;
+mod self_applier;
+mod soup_stats;
+mod symbolic_base;
+mod symbolic_edit;
+mod symbolic_executor;
+mod symbolic_path;
+mod symbolic_strand;
+
+pub use autocatalytic_set::AutocatalyticSet;
+pub use condition::Condition;
+pub use counterexample::Counterexample;
+pub use equivalence_checker::EquivalenceChecker;
+pub use outcome::Outcome;
+pub use pathway::Pathway;
+pub use pathway_finder::PathwayFinder;
+pub use raf_finder::RafFinder;
+pub use reaction::Reaction;
+pub use replication_class::ReplicationClass;
+pub use self_application::SelfApplication;
+pub use self_applier::SelfApplier;
+pub use soup_stats::SoupStats;
+pub use symbolic_base::SymbolicBase;
+pub use symbolic_edit::SymbolicEdit;
+pub use symbolic_executor::SymbolicExecutor;
+pub use symbolic_path::SymbolicPath;
+pub use symbolic_strand::SymbolicStrand;
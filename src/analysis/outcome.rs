@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// How a symbolic path terminated. Steps are indices into the enzyme's amino acids.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Completed,
+    EndOfStrand { step: usize },
+    EmptyComplement { step: usize },
+    FlankLimit { step: usize },
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Outcome::Completed => write!(f, "completed"),
+            Outcome::EndOfStrand { step } => {
+                write!(f, "halted at step {}: reached end of strand", step)
+            }
+            Outcome::EmptyComplement { step } => {
+                write!(f, "halted at step {}: switched to empty complement", step)
+            }
+            Outcome::FlankLimit { step } => {
+                write!(f, "abandoned at step {}: exceeded flank limit", step)
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::{json, Value};
+
+use crate::analysis::Reaction;
+use crate::typogenetics::Strand;
+
+/// Reactions that produce a target from a set of starting strands, in an order where
+/// every catalyst and reactant is a starting strand or a product of an earlier step.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Pathway {
+    pub start: Vec<Strand>,
+    pub target: Strand,
+    pub steps: Vec<Reaction>,
+}
+
+impl Pathway {
+    pub fn to_json(&self) -> Value {
+        let start: Vec<String> = self.start.iter().map(|s| s.to_string()).collect();
+        let steps: Vec<Value> = self.steps.iter().map(Reaction::to_json).collect();
+        json!({
+            "start": start,
+            "target": self.target.to_string(),
+            "steps": steps,
+        })
+    }
+}
+
+impl Display for Pathway {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let start: Vec<String> = self.start.iter().map(|s| s.to_string()).collect();
+        writeln!(f, "Start: {}", start.join(", "))?;
+        for (i, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{}. {}", i + 1, step)?;
+        }
+        write!(
+            f,
+            "Target: {} after {} steps",
+            self.target,
+            self.steps.len()
+        )
+    }
+}
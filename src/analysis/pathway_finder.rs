@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::{Pathway, Reaction};
+use crate::search::{
+    Checkpoint, CheckpointState, GenerationLimits, RewriteCache, SearchAlgos, StopCondition,
+};
+use crate::typogenetics::Strand;
+
+/// Finds how a target strand can arise from a set of starting strands.
+pub struct PathwayFinder;
+
+/// A reaction between numbered strands, keeping only the products it adds.
+struct Link {
+    inputs: [usize; 2],
+    outputs: Vec<usize>,
+    reaction: usize,
+}
+
+impl PathwayFinder {
+    /// Run the generational search of `SearchAlgos::run_generations` until the target is
+    /// produced, then find the pathway with the fewest reactions among those the search
+    /// ran. Products dropped by a limit never joined the set, so no step relies on them.
+    /// Returns `None` if the target is not produced within the limits.
+    pub fn find(
+        start: &[Strand],
+        target: &Strand,
+        limits: GenerationLimits,
+        cache: &mut RewriteCache,
+    ) -> Option<Pathway> {
+        let mut strands: Vec<Strand> = Vec::new();
+        for strand in start {
+            if !strands.contains(strand) {
+                strands.push(strand.clone());
+            }
+        }
+
+        let mut reactions = Vec::new();
+        let checkpoint = Checkpoint::start(&strands, CheckpointState::Generational { n_done: 0 });
+        let result = SearchAlgos::resume_generations(
+            checkpoint,
+            limits,
+            cache,
+            None,
+            &[StopCondition::Target(target.clone())],
+            |summary| log::info!("{}", summary),
+            |catalyst, enzyme, reactant, products| {
+                // A reaction that only gives back its inputs never helps
+                if products.iter().all(|p| p == catalyst || p == reactant) {
+                    return;
+                }
+                reactions.push(Reaction {
+                    catalyst: catalyst.clone(),
+                    enzyme: enzyme.clone(),
+                    reactant: reactant.clone(),
+                    products: products.to_vec(),
+                });
+            },
+        )
+        .expect("Searches without checkpoints cannot fail");
+
+        let stop = result.stop?;
+        log::info!("Found target in generation {}", stop.iteration);
+        let steps = Self::find_steps(&strands, target, &result.strands, &reactions)?;
+        Some(Pathway {
+            start: start.to_vec(),
+            target: target.clone(),
+            steps,
+        })
+    }
+
+    /// The fewest reactions that produce the target from the start strands, in an order
+    /// they can run in, counting only products among the kept strands.
+    fn find_steps(
+        start: &[Strand],
+        target: &Strand,
+        kept: &[Strand],
+        reactions: &[Reaction],
+    ) -> Option<Vec<Reaction>> {
+        let mut ids: HashMap<&Strand, usize> = HashMap::new();
+        for strand in start.iter().chain(kept) {
+            let id = ids.len();
+            ids.entry(strand).or_insert(id);
+        }
+        let target_id = *ids.get(target)?;
+        let start_ids: Vec<usize> = start.iter().map(|strand| ids[strand]).collect();
+        if start_ids.contains(&target_id) {
+            return Some(Vec::new());
+        }
+
+        let mut links = Vec::new();
+        let mut seen = HashSet::new();
+        for (i, reaction) in reactions.iter().enumerate() {
+            let (Some(&catalyst), Some(&reactant)) =
+                (ids.get(&reaction.catalyst), ids.get(&reaction.reactant))
+            else {
+                continue;
+            };
+            let inputs = [catalyst, reactant];
+            let mut outputs: Vec<usize> = reaction
+                .products
+                .iter()
+                .filter_map(|product| ids.get(product).copied())
+                .filter(|output| !inputs.contains(output))
+                .collect();
+            outputs.sort_unstable();
+            outputs.dedup();
+            // Enzymes of a strand often have the same effect, which is one link
+            if !outputs.is_empty() && seen.insert((inputs, outputs.clone())) {
+                links.push(Link {
+                    inputs,
+                    outputs,
+                    reaction: i,
+                });
+            }
+        }
+
+        let before = Self::count_before(ids.len(), &start_ids, &links);
+        let after = Self::count_after(ids.len(), target_id, &links);
+        let first_bound = before
+            .iter()
+            .zip(&after)
+            .filter(|(_, after)| **after == 0)
+            .map(|(before, _)| *before)
+            .min()?;
+        // A pathway of n links only holds links with at most n links before and after
+        // them, so the first bound that admits a pathway gives one with the fewest links
+        (first_bound..=links.len()).find_map(|bound| {
+            let path = Self::search_within(bound, &start_ids, target_id, &links, &before, &after)?;
+            Some(
+                path.iter()
+                    .map(|&link| reactions[links[link].reaction].clone())
+                    .collect(),
+            )
+        })
+    }
+
+    /// For each link, the fewest links that must run before it, counting itself, or
+    /// `usize::MAX` if it can never run.
+    fn count_before(n_strands: usize, start_ids: &[usize], links: &[Link]) -> Vec<usize> {
+        let mut strand_before = vec![usize::MAX; n_strands];
+        for &id in start_ids {
+            strand_before[id] = 0;
+        }
+        let mut before = vec![usize::MAX; links.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (link, count) in links.iter().zip(&mut before) {
+                let inputs = link.inputs.map(|id| strand_before[id]);
+                if inputs.contains(&usize::MAX) {
+                    continue;
+                }
+                *count = 1 + inputs[0].max(inputs[1]);
+                for &output in &link.outputs {
+                    if *count < strand_before[output] {
+                        strand_before[output] = *count;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        before
+    }
+
+    /// For each link, the fewest links that must run after it until the target exists,
+    /// or `usize::MAX` if none of its products lead to the target.
+    fn count_after(n_strands: usize, target_id: usize, links: &[Link]) -> Vec<usize> {
+        let mut strand_after = vec![usize::MAX; n_strands];
+        strand_after[target_id] = 0;
+        let mut after = vec![usize::MAX; links.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (link, count) in links.iter().zip(&mut after) {
+                let Some(&min) = link.outputs.iter().map(|&id| &strand_after[id]).min() else {
+                    continue;
+                };
+                if min >= *count {
+                    continue;
+                }
+                *count = min;
+                changed = true;
+                for &input in &link.inputs {
+                    strand_after[input] = strand_after[input].min(min + 1);
+                }
+            }
+        }
+        after
+    }
+
+    /// Search breadth first for a pathway of at most `bound` links, each adding a strand
+    /// to the ones the earlier links made available.
+    fn search_within(
+        bound: usize,
+        start_ids: &[usize],
+        target_id: usize,
+        links: &[Link],
+        before: &[usize],
+        after: &[usize],
+    ) -> Option<Vec<usize>> {
+        let candidates: Vec<usize> = (0..links.len())
+            .filter(|&i| before[i].saturating_add(after[i]) <= bound)
+            .collect();
+        let mut available = start_ids.to_vec();
+        available.sort_unstable();
+        let mut seen = HashSet::from([available.clone()]);
+        let mut layer = vec![(available, Vec::new())];
+
+        for depth in 0..bound {
+            let mut next = Vec::new();
+            for (available, path) in &layer {
+                for &i in &candidates {
+                    let link = &links[i];
+                    if depth + 1 + after[i] > bound
+                        || link
+                            .inputs
+                            .iter()
+                            .any(|id| available.binary_search(id).is_err())
+                    {
+                        continue;
+                    }
+                    let mut state = available.clone();
+                    for &output in &link.outputs {
+                        if let Err(pos) = state.binary_search(&output) {
+                            state.insert(pos, output);
+                        }
+                    }
+                    if state.len() == available.len() {
+                        continue;
+                    }
+                    let mut path = path.clone();
+                    path.push(i);
+                    if link.outputs.contains(&target_id) {
+                        return Some(path);
+                    }
+                    if seen.insert(state.clone()) {
+                        next.push((state, path));
+                    }
+                }
+            }
+            layer = next;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typogenetics::Enzyme;
+
+    fn limits() -> GenerationLimits {
+        GenerationLimits::new(4, 2_000, 60)
+    }
+
+    #[test]
+    fn steps_only_use_known_strands() {
+        let start = [Strand::from_string("ATAGCGAATAGGATAATG")];
+        let target = Strand::from_string("ATA");
+        let pathway =
+            PathwayFinder::find(&start, &target, limits(), &mut RewriteCache::new(0)).unwrap();
+
+        let mut known: HashSet<Strand> = start.iter().cloned().collect();
+        for step in &pathway.steps {
+            assert!(known.contains(&step.catalyst));
+            assert!(known.contains(&step.reactant));
+            known.extend(step.products.iter().cloned());
+        }
+        assert!(pathway.steps.last().unwrap().products.contains(&target));
+    }
+
+    #[test]
+    fn target_among_start_needs_no_steps() {
+        let start = [Strand::from_string("ATAGCGAATAGGATAATG")];
+        let pathway =
+            PathwayFinder::find(&start, &start[0], limits(), &mut RewriteCache::new(0)).unwrap();
+        assert!(pathway.steps.is_empty());
+    }
+
+    fn reaction(catalyst: &str, reactant: &str, products: &[&str]) -> Reaction {
+        Reaction {
+            catalyst: Strand::from_string(catalyst),
+            enzyme: Enzyme::from_string("cut"),
+            reactant: Strand::from_string(reactant),
+            products: products.iter().map(|p| Strand::from_string(p)).collect(),
+        }
+    }
+
+    fn strands(strs: &[&str]) -> Vec<Strand> {
+        strs.iter().map(|s| Strand::from_string(s)).collect()
+    }
+
+    #[test]
+    fn finds_fewest_reactions_rather_than_first_producers() {
+        // The first reactions to produce each strand take three steps to the target
+        let reactions = [
+            reaction("A", "A", &["C"]),
+            reaction("C", "C", &["G"]),
+            reaction("G", "C", &["T"]),
+            reaction("A", "C", &["T"]),
+        ];
+        let steps = PathwayFinder::find_steps(
+            &strands(&["A"]),
+            &Strand::from_string("T"),
+            &strands(&["A", "C", "G", "T"]),
+            &reactions,
+        )
+        .unwrap();
+        assert_eq!(steps, vec![reactions[0].clone(), reactions[3].clone()]);
+    }
+
+    #[test]
+    fn steps_only_use_kept_products() {
+        // CC was too long to keep, so only the longer route makes the target
+        let reactions = [
+            reaction("A", "A", &["CC"]),
+            reaction("CC", "A", &["T"]),
+            reaction("A", "A", &["G"]),
+            reaction("G", "G", &["C"]),
+            reaction("C", "A", &["T"]),
+        ];
+        let steps = PathwayFinder::find_steps(
+            &strands(&["A"]),
+            &Strand::from_string("T"),
+            &strands(&["A", "G", "C", "T"]),
+            &reactions,
+        )
+        .unwrap();
+        assert_eq!(steps, reactions[2..].to_vec());
+    }
+
+    #[test]
+    fn unreachable_target_has_no_pathway() {
+        let start = [Strand::from_string("ATAGCGAATAGGATAATG")];
+        let target = Strand::from_string("GGGGGGGGGG");
+        let limits = GenerationLimits {
+            max_generations: 2,
+            ..limits()
+        };
+        assert!(PathwayFinder::find(&start, &target, limits, &mut RewriteCache::new(0)).is_none());
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::{AutocatalyticSet, Reaction};
+use crate::typogenetics::Strand;
+
+/// Finds autocatalytic sets in a network of reactions using the RAF algorithm of
+/// Hordijk and Steel.
+pub struct RafFinder;
+
+/// A network with strands replaced by their index, so closures are cheap to compute.
+struct IndexedNetwork {
+    n_strands: usize,
+    food: Vec<usize>,
+    reactions: Vec<IndexedReaction>,
+}
+
+struct IndexedReaction {
+    catalyst: usize,
+    reactant: usize,
+    products: Vec<usize>,
+}
+
+impl RafFinder {
+    /// The maximal RAF, the union of all RAFs, or `None` if the network has no RAF.
+    pub fn find_max(food: &[Strand], reactions: &[Reaction]) -> Option<AutocatalyticSet> {
+        let network = Self::index(food, reactions);
+        let all: Vec<usize> = (0..reactions.len()).collect();
+        let max_raf = Self::reduce(&network, all);
+        if max_raf.is_empty() {
+            None
+        } else {
+            Some(Self::to_set(food, reactions, &max_raf))
+        }
+    }
+
+    /// Irreducible RAFs inside a maximal RAF, which stop being RAFs if any reaction is
+    /// removed. Each is found by removing reactions one at a time while a RAF remains,
+    /// starting from a different reaction each time, so some irreducible RAFs of a large
+    /// network may be missed. Returns at most `max_subsets` distinct sets.
+    pub fn find_irreducible(
+        food: &[Strand],
+        max_raf: &AutocatalyticSet,
+        max_subsets: usize,
+    ) -> Vec<AutocatalyticSet> {
+        let network = Self::index(food, &max_raf.reactions);
+        let n = network.reactions.len();
+        let mut found: Vec<Vec<usize>> = Vec::new();
+
+        for first in 0..n {
+            if found.len() >= max_subsets {
+                break;
+            }
+            // Sets found so far already cover every start they contain
+            if found.iter().any(|f| f.contains(&first)) {
+                continue;
+            }
+            let mut reactions: Vec<usize> = (first..n).chain(0..first).collect();
+
+            let mut i = 0;
+            while i < reactions.len() {
+                let mut candidate = reactions.clone();
+                candidate.remove(i);
+                let raf = Self::reduce(&network, candidate);
+                if raf.is_empty() {
+                    i += 1;
+                } else {
+                    reactions = raf;
+                }
+            }
+
+            reactions.sort_unstable();
+            if !found.contains(&reactions) {
+                found.push(reactions);
+            }
+        }
+
+        found
+            .iter()
+            .map(|raf| Self::to_set(food, &max_raf.reactions, raf))
+            .collect()
+    }
+
+    /// Remove reactions whose catalyst or reactant cannot be made from the food by the
+    /// remaining reactions until none are left to remove, leaving the maximal RAF.
+    fn reduce(network: &IndexedNetwork, mut reactions: Vec<usize>) -> Vec<usize> {
+        loop {
+            let closure = Self::closure(network, &reactions);
+            let n_reactions = reactions.len();
+            reactions.retain(|&r| {
+                let reaction = &network.reactions[r];
+                closure[reaction.catalyst] && closure[reaction.reactant]
+            });
+            if reactions.len() == n_reactions {
+                return reactions;
+            }
+        }
+    }
+
+    /// Every strand reachable from the food by reactions whose reactant is reachable,
+    /// ignoring catalysts.
+    fn closure(network: &IndexedNetwork, reactions: &[usize]) -> Vec<bool> {
+        let mut closure = vec![false; network.n_strands];
+        for &strand in &network.food {
+            closure[strand] = true;
+        }
+        loop {
+            let mut changed = false;
+            for &r in reactions {
+                let reaction = &network.reactions[r];
+                if closure[reaction.reactant] {
+                    for &product in &reaction.products {
+                        changed |= !closure[product];
+                        closure[product] = true;
+                    }
+                }
+            }
+            if !changed {
+                return closure;
+            }
+        }
+    }
+
+    fn index(food: &[Strand], reactions: &[Reaction]) -> IndexedNetwork {
+        let mut ids: HashMap<&Strand, usize> = HashMap::new();
+        let mut id = |strand| {
+            let n_strands = ids.len();
+            *ids.entry(strand).or_insert(n_strands)
+        };
+        let food = food.iter().map(&mut id).collect();
+        let reactions = reactions
+            .iter()
+            .map(|r| IndexedReaction {
+                catalyst: id(&r.catalyst),
+                reactant: id(&r.reactant),
+                products: r.products.iter().map(&mut id).collect(),
+            })
+            .collect();
+        IndexedNetwork {
+            n_strands: ids.len(),
+            food,
+            reactions,
+        }
+    }
+
+    fn to_set(food: &[Strand], reactions: &[Reaction], subset: &[usize]) -> AutocatalyticSet {
+        let reactions: Vec<Reaction> = subset.iter().map(|&r| reactions[r].clone()).collect();
+        let mut strands = Vec::new();
+        let mut seen = HashSet::new();
+        let produced = reactions.iter().flat_map(|r| &r.products);
+        for strand in food.iter().chain(produced) {
+            if seen.insert(strand) {
+                strands.push(strand.clone());
+            }
+        }
+        AutocatalyticSet { reactions, strands }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typogenetics::Enzyme;
+
+    fn reaction(catalyst: &str, reactant: &str, product: &str) -> Reaction {
+        Reaction {
+            catalyst: Strand::from_string(catalyst),
+            enzyme: Enzyme::from_string("cut"),
+            reactant: Strand::from_string(reactant),
+            products: vec![Strand::from_string(product)],
+        }
+    }
+
+    fn food() -> Vec<Strand> {
+        vec![Strand::from_string("A"), Strand::from_string("C")]
+    }
+
+    /// Two irreducible RAFs, one reaction that depends on one of them, and reactions
+    /// that fall out of the maximal RAF in the first and second reduction rounds.
+    fn network() -> Vec<Reaction> {
+        vec![
+            // Catalyses its own production from food
+            reaction("GA", "A", "GA"),
+            // Needs the product of the reaction above
+            reaction("A", "GA", "GC"),
+            // The reactant is never produced
+            reaction("GC", "GG", "GT"),
+            // The catalyst is never produced
+            reaction("TA", "C", "TC"),
+            // The catalyst is only produced by the reaction above
+            reaction("TC", "C", "TG"),
+            // Catalysed by food
+            reaction("C", "A", "TT"),
+        ]
+    }
+
+    #[test]
+    fn max_raf_drops_reactions_until_nothing_changes() {
+        let reactions = network();
+        let max_raf = RafFinder::find_max(&food(), &reactions).unwrap();
+
+        assert_eq!(
+            max_raf.reactions,
+            vec![
+                reactions[0].clone(),
+                reactions[1].clone(),
+                reactions[5].clone()
+            ]
+        );
+        let strands: Vec<String> = max_raf.strands.iter().map(|s| s.to_string()).collect();
+        assert_eq!(strands, vec!["A", "C", "GA", "GC", "TT"]);
+    }
+
+    #[test]
+    fn network_without_raf_has_no_max_raf() {
+        let reactions = network();
+        let without_raf = [
+            reactions[2].clone(),
+            reactions[3].clone(),
+            reactions[4].clone(),
+        ];
+        assert!(RafFinder::find_max(&food(), &without_raf).is_none());
+    }
+
+    #[test]
+    fn finds_each_irreducible_raf() {
+        let reactions = network();
+        let max_raf = RafFinder::find_max(&food(), &reactions).unwrap();
+        let mut irreducible: Vec<Vec<Reaction>> =
+            RafFinder::find_irreducible(&food(), &max_raf, 10)
+                .into_iter()
+                .map(|set| set.reactions)
+                .collect();
+        irreducible.sort_by_key(|reactions| reactions[0].to_string());
+
+        assert_eq!(
+            irreducible,
+            vec![vec![reactions[5].clone()], vec![reactions[0].clone()]]
+        );
+    }
+
+    #[test]
+    fn stops_at_max_subsets() {
+        let reactions = network();
+        let max_raf = RafFinder::find_max(&food(), &reactions).unwrap();
+        assert_eq!(RafFinder::find_irreducible(&food(), &max_raf, 1).len(), 1);
+    }
+}
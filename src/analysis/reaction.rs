@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::{json, Value};
+
+use crate::typogenetics::{Enzyme, Strand};
+
+/// An enzyme coded by the catalyst strand rewriting the reactant strand into products.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Reaction {
+    pub catalyst: Strand,
+    pub enzyme: Enzyme,
+    pub reactant: Strand,
+    pub products: Vec<Strand>,
+}
+
+impl Reaction {
+    pub fn to_json(&self) -> Value {
+        let products: Vec<String> = self.products.iter().map(|s| s.to_string()).collect();
+        json!({
+            "catalyst": self.catalyst.to_string(),
+            "enzyme": self.enzyme.to_string(),
+            "reactant": self.reactant.to_string(),
+            "products": products,
+        })
+    }
+}
+
+impl Display for Reaction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let products: Vec<String> = self.products.iter().map(|s| s.to_string()).collect();
+        write!(
+            f,
+            "{} [{}]: {} -> {}",
+            self.catalyst,
+            self.enzyme,
+            self.reactant,
+            products.join(" + ")
+        )
+    }
+}
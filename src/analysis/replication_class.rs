@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::analysis::SelfApplier;
+use crate::typogenetics::Strand;
+
+/// What a strand does to itself when its enzymes are applied to it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReplicationClass {
+    /// No enzyme changes the strand, including strands that code for no enzymes.
+    Inert,
+    /// Some enzyme changes the strand, but none copies it.
+    Active,
+    /// Some enzyme copies the strand, see `SelfApplier::is_self_replicator`.
+    SelfReplicator,
+}
+
+impl ReplicationClass {
+    pub fn of(strand: &Strand) -> ReplicationClass {
+        if SelfApplier::is_self_replicator(strand) {
+            return ReplicationClass::SelfReplicator;
+        }
+        let is_active = SelfApplier::apply(strand)
+            .iter()
+            .any(|application| application.products != std::slice::from_ref(strand));
+        if is_active {
+            ReplicationClass::Active
+        } else {
+            ReplicationClass::Inert
+        }
+    }
+}
+
+impl FromStr for ReplicationClass {
+    type Err = String;
+
+    fn from_str(class_str: &str) -> Result<ReplicationClass, String> {
+        match class_str {
+            "inert" => Ok(ReplicationClass::Inert),
+            "active" => Ok(ReplicationClass::Active),
+            "self-replicator" => Ok(ReplicationClass::SelfReplicator),
+            _ => Err(format!(
+                "Invalid replication class '{}', expected inert, active or self-replicator",
+                class_str
+            )),
+        }
+    }
+}
+
+impl Display for ReplicationClass {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            ReplicationClass::Inert => "inert",
+            ReplicationClass::Active => "active",
+            ReplicationClass::SelfReplicator => "self-replicator",
+        };
+        write!(f, "{}", name)
+    }
+}
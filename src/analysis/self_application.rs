@@ -0,0 +1,11 @@
+use crate::typogenetics::{Base, Enzyme, Strand};
+
+/// One enzyme coded by a strand, applied back to that strand.
+#[derive(Debug, Clone)]
+pub struct SelfApplication {
+    pub strand: Strand,
+    pub enzyme: Enzyme,
+    pub binding_base: Base,
+    pub binding_site: Option<usize>,
+    pub products: Vec<Strand>,
+}
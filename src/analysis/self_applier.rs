@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::analysis::SelfApplication;
+use crate::typogenetics::{Folder, Rewriter, Strand, Translator};
+
+/// Runs the classic typogenetics cycle, where a strand is rewritten by its own enzymes.
+pub struct SelfApplier;
+
+impl SelfApplier {
+    /// Translate a strand and apply each of its enzymes to the original strand.
+    pub fn apply(strand: &Strand) -> Vec<SelfApplication> {
+        Translator::translate(strand)
+            .into_iter()
+            .map(|enzyme| {
+                let binding_base = Folder::get_binding_affinity(Folder::fold(&enzyme));
+                let binding_site = Folder::get_binding_site(&enzyme, strand);
+                let products = Rewriter::rewrite(&enzyme, strand);
+                SelfApplication {
+                    strand: strand.clone(),
+                    enzyme,
+                    binding_base,
+                    binding_site,
+                    products,
+                }
+            })
+            .collect()
+    }
+
+    /// Whether one of the strand's enzymes turns it into at least two copies of itself,
+    /// or into a copy of itself and a copy of its complement. The complement is read in
+    /// the opposite direction, as the rewriter releases it.
+    pub fn is_self_replicator(strand: &Strand) -> bool {
+        let complement = strand.get_reverse_complement();
+
+        Self::apply(strand).iter().any(|application| {
+            let products = &application.products;
+            let n_copies = products.iter().filter(|&p| p == strand).count();
+            products.len() >= 2
+                && n_copies >= 1
+                && (n_copies >= 2 || products.contains(&complement))
+        })
+    }
+
+    /// Apply each strand to itself for a number of generations. The strands of each
+    /// generation are the products of the last that have not been seen before, so the
+    /// cycle stops early once no new strands appear.
+    pub fn iterate(strand: &Strand, n_generations: usize) -> Vec<Vec<SelfApplication>> {
+        let mut generations = Vec::new();
+        let mut seen = HashSet::from([strand.clone()]);
+        let mut current = vec![strand.clone()];
+
+        for _ in 0..n_generations {
+            if current.is_empty() {
+                break;
+            }
+            let applications: Vec<SelfApplication> = current.iter().flat_map(Self::apply).collect();
+
+            current = Vec::new();
+            for product in applications.iter().flat_map(|a| &a.products) {
+                if !product.is_empty() && seen.insert(product.clone()) {
+                    current.push(product.clone());
+                }
+            }
+            generations.push(applications);
+        }
+
+        generations
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use serde_json::{json, Value};
+
+use crate::typogenetics::{
+    AminoAcid, BaseComposition, Enzyme, Folder, Orientation, Strand, Translator,
+};
+
+/// Statistics of a set of strands and of the enzymes they code for, such as the strands
+/// discovered by a simulation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SoupStats {
+    pub n_strands: usize,
+    /// Number of strands of each length.
+    pub lengths: BTreeMap<usize, usize>,
+    pub composition: BaseComposition,
+    pub n_enzymes: usize,
+    pub n_without_enzymes: usize,
+    /// Occurrences of each amino acid across all enzymes, in the order of `AminoAcid::ALL`.
+    pub amino_acids: Vec<(AminoAcid, usize)>,
+    /// Number of enzymes folding to each orientation, in the order of `Orientation::ALL`.
+    /// The orientation decides the base an enzyme binds to.
+    pub orientations: Vec<(Orientation, usize)>,
+    /// The most common enzymes and their number of occurrences, most common first and
+    /// ties in enzyme order.
+    pub top_enzymes: Vec<(Enzyme, usize)>,
+}
+
+impl SoupStats {
+    /// Number of bins of the length histogram in the report.
+    const N_LENGTH_BINS: usize = 10;
+
+    /// Gather statistics of the strands, keeping the `top_k` most common enzymes.
+    pub fn from_strands(strands: &[Strand], top_k: usize) -> SoupStats {
+        let mut lengths = BTreeMap::new();
+        let mut composition = BaseComposition::default();
+        let mut n_without_enzymes = 0;
+        let mut amino_acids: HashMap<AminoAcid, usize> = HashMap::new();
+        let mut orientations = [0; 4];
+        let mut enzyme_counts: HashMap<Enzyme, usize> = HashMap::new();
+
+        for strand in strands {
+            *lengths.entry(strand.len()).or_insert(0) += 1;
+            composition.merge(&strand.get_composition());
+            let enzymes = Translator::translate(strand);
+            if enzymes.is_empty() {
+                n_without_enzymes += 1;
+            }
+            for enzyme in enzymes {
+                for &amino_acid in enzyme.iter_amino_acids() {
+                    *amino_acids.entry(amino_acid).or_insert(0) += 1;
+                }
+                let orientation = Folder::fold(&enzyme);
+                let i = Orientation::ALL
+                    .iter()
+                    .position(|&o| o == orientation)
+                    .expect("Every orientation is listed");
+                orientations[i] += 1;
+                *enzyme_counts.entry(enzyme).or_insert(0) += 1;
+            }
+        }
+
+        let mut top_enzymes: Vec<(Enzyme, usize)> = enzyme_counts.into_iter().collect();
+        top_enzymes.sort_by_key(|(enzyme, count)| (std::cmp::Reverse(*count), enzyme.to_string()));
+        top_enzymes.truncate(top_k);
+
+        SoupStats {
+            n_strands: strands.len(),
+            lengths,
+            composition,
+            n_enzymes: orientations.iter().sum(),
+            n_without_enzymes,
+            amino_acids: AminoAcid::ALL
+                .iter()
+                .map(|a| (*a, amino_acids.get(a).copied().unwrap_or(0)))
+                .collect(),
+            orientations: Orientation::ALL.into_iter().zip(orientations).collect(),
+            top_enzymes,
+        }
+    }
+
+    pub fn get_mean_length(&self) -> f64 {
+        Self::get_fraction(self.composition.get_total(), self.n_strands)
+    }
+
+    /// The length at or below which a fraction `q` of the strands fall, or `None` if
+    /// there are no strands.
+    pub fn get_length_quantile(&self, q: f64) -> Option<usize> {
+        let rank = ((q * self.n_strands as f64).ceil() as usize).max(1);
+        let mut n_seen = 0;
+        for (&length, &count) in &self.lengths {
+            n_seen += count;
+            if n_seen >= rank {
+                return Some(length);
+            }
+        }
+        None
+    }
+
+    pub fn get_fraction_without_enzymes(&self) -> f64 {
+        Self::get_fraction(self.n_without_enzymes, self.n_strands)
+    }
+
+    pub fn to_json(&self) -> Value {
+        let lengths: serde_json::Map<String, Value> = self
+            .lengths
+            .iter()
+            .map(|(length, count)| (length.to_string(), json!(count)))
+            .collect();
+        let bases: serde_json::Map<String, Value> = self
+            .composition
+            .get_counts()
+            .iter()
+            .map(|(base, count)| (base.to_string(), json!(count)))
+            .collect();
+        let amino_acids: serde_json::Map<String, Value> = self
+            .amino_acids
+            .iter()
+            .map(|(amino_acid, count)| (amino_acid.to_string(), json!(count)))
+            .collect();
+        let orientations: Vec<Value> = self
+            .orientations
+            .iter()
+            .map(|&(orientation, count)| {
+                json!({
+                    "orientation": format!("{:?}", orientation),
+                    "binds": Folder::get_binding_affinity(orientation).to_string(),
+                    "count": count,
+                })
+            })
+            .collect();
+        let top_enzymes: Vec<Value> = self
+            .top_enzymes
+            .iter()
+            .map(|(enzyme, count)| json!({"enzyme": enzyme.to_string(), "count": count}))
+            .collect();
+        json!({
+            "strands": self.n_strands,
+            "lengths": lengths,
+            "bases": bases,
+            "enzymes": self.n_enzymes,
+            "strands_without_enzymes": self.n_without_enzymes,
+            "amino_acids": amino_acids,
+            "orientations": orientations,
+            "top_enzymes": top_enzymes,
+        })
+    }
+
+    fn get_fraction(count: usize, total: usize) -> f64 {
+        if total == 0 {
+            0.0
+        } else {
+            count as f64 / total as f64
+        }
+    }
+
+    fn write_count(f: &mut Formatter, label: &str, count: usize, total: usize) -> std::fmt::Result {
+        writeln!(
+            f,
+            "- {}: {} ({:.1}%)",
+            label,
+            count,
+            100.0 * Self::get_fraction(count, total)
+        )
+    }
+}
+
+impl Display for SoupStats {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (Some(&min), Some(&max)) = (self.lengths.keys().next(), self.lengths.keys().last())
+        else {
+            return write!(f, "No strands");
+        };
+
+        writeln!(f, "Strands: {}", self.n_strands)?;
+        writeln!(
+            f,
+            "Length: min {}, quartiles {}/{}/{}, max {}, mean {:.2}",
+            min,
+            self.get_length_quantile(0.25).unwrap_or(min),
+            self.get_length_quantile(0.5).unwrap_or(min),
+            self.get_length_quantile(0.75).unwrap_or(min),
+            max,
+            self.get_mean_length()
+        )?;
+        let bin_width = (max - min).div_ceil(Self::N_LENGTH_BINS).max(1);
+        let mut bin_start = min;
+        while bin_start <= max {
+            let bin_end = bin_start + bin_width - 1;
+            let count = self
+                .lengths
+                .range(bin_start..=bin_end)
+                .map(|(_, c)| c)
+                .sum();
+            let label = if bin_width == 1 {
+                bin_start.to_string()
+            } else {
+                format!("{}-{}", bin_start, bin_end)
+            };
+            Self::write_count(f, &label, count, self.n_strands)?;
+            bin_start += bin_width;
+        }
+
+        let n_bases = self.composition.get_total();
+        writeln!(f, "Bases: {}", n_bases)?;
+        for (base, count) in self.composition.get_counts() {
+            Self::write_count(f, &base.to_string(), count, n_bases)?;
+        }
+
+        writeln!(
+            f,
+            "Enzymes: {}, strands without enzymes: {} ({:.1}%)",
+            self.n_enzymes,
+            self.n_without_enzymes,
+            100.0 * self.get_fraction_without_enzymes()
+        )?;
+        let n_amino_acids = self.amino_acids.iter().map(|(_, count)| count).sum();
+        writeln!(f, "Amino acids: {}", n_amino_acids)?;
+        for (amino_acid, count) in &self.amino_acids {
+            Self::write_count(f, &amino_acid.to_string(), *count, n_amino_acids)?;
+        }
+        writeln!(f, "Orientations and binding affinities:")?;
+        for &(orientation, count) in &self.orientations {
+            let label = format!(
+                "{:?} binds {}",
+                orientation,
+                Folder::get_binding_affinity(orientation)
+            );
+            Self::write_count(f, &label, count, self.n_enzymes)?;
+        }
+        write!(f, "Top {} enzymes:", self.top_enzymes.len())?;
+        for (enzyme, count) in &self.top_enzymes {
+            write!(f, "\n- {}: {}", enzyme, count)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::typogenetics::{Base, BaseType};
+
+/// A base whose identity may be unknown. Original bases are named by their offset
+/// from the binding site, so `x0` is the base the enzyme binds to, `x+1` is the
+/// base to its right and `x-1` the base to its left.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SymbolicBase {
+    Original { offset: i32, complement: bool },
+    Inserted(Base),
+}
+
+impl Display for SymbolicBase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SymbolicBase::Original { offset, complement } => {
+                let mark = if *complement { "'" } else { "" };
+                if *offset == 0 {
+                    write!(f, "x0{}", mark)
+                } else {
+                    write!(f, "x{:+}{}", offset, mark)
+                }
+            }
+            SymbolicBase::Inserted(base) => write!(f, "{}", base),
+        }
+    }
+}
+
+impl SymbolicBase {
+    pub fn original(offset: i32) -> SymbolicBase {
+        SymbolicBase::Original {
+            offset,
+            complement: false,
+        }
+    }
+
+    pub fn get_complement(&self) -> SymbolicBase {
+        match self {
+            SymbolicBase::Original { offset, complement } => SymbolicBase::Original {
+                offset: *offset,
+                complement: !complement,
+            },
+            SymbolicBase::Inserted(base) => SymbolicBase::Inserted(base.get_complement()),
+        }
+    }
+
+    pub fn get_offset(&self) -> Option<i32> {
+        match self {
+            SymbolicBase::Original { offset, .. } => Some(*offset),
+            SymbolicBase::Inserted(_) => None,
+        }
+    }
+
+    /// Resolve this base to a concrete base if it is known given the binding base.
+    pub fn resolve(&self, binding_base: Base) -> Option<Base> {
+        match self {
+            SymbolicBase::Original {
+                offset: 0,
+                complement,
+            } => {
+                if *complement {
+                    Some(binding_base.get_complement())
+                } else {
+                    Some(binding_base)
+                }
+            }
+            SymbolicBase::Original { .. } => None,
+            SymbolicBase::Inserted(base) => Some(*base),
+        }
+    }
+
+    /// The base type that the underlying original base must have for this base to be
+    /// of the given type. Complementing a base swaps purines and pyrimidines.
+    pub fn get_original_type(&self, base_type: BaseType) -> BaseType {
+        match self {
+            SymbolicBase::Original {
+                complement: true, ..
+            } => match base_type {
+                BaseType::Purine => BaseType::Pyrimidine,
+                BaseType::Pyrimidine => BaseType::Purine,
+            },
+            _ => base_type,
+        }
+    }
+}
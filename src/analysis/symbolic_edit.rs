@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::analysis::SymbolicBase;
+use crate::typogenetics::Base;
+
+/// A single change an enzyme makes to a symbolic strand.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SymbolicEdit {
+    Cut { after: SymbolicBase },
+    Delete { base: SymbolicBase },
+    Switch { at: SymbolicBase },
+    Copy { base: SymbolicBase },
+    Insert { base: Base, after: SymbolicBase },
+}
+
+impl Display for SymbolicEdit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SymbolicEdit::Cut { after } => write!(f, "cut after {}", after),
+            SymbolicEdit::Delete { base } => write!(f, "delete {}", base),
+            SymbolicEdit::Switch { at } => write!(f, "switch strands at {}", at),
+            SymbolicEdit::Copy { base } => write!(f, "copy {}", base),
+            SymbolicEdit::Insert { base, after } => write!(f, "insert {} after {}", base, after),
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use std::collections::BTreeSet;
+
+use crate::analysis::{
+    Condition, Outcome, SymbolicBase, SymbolicEdit, SymbolicPath, SymbolicStrand,
+};
+use crate::typogenetics::{AminoAcid, Base, BaseType, Enzyme, Folder, Rewriter};
+
+#[derive(Clone)]
+struct SymbolicPair {
+    bind: Option<SymbolicBase>,
+    comp: Option<SymbolicBase>,
+}
+
+#[derive(Clone)]
+struct SymbolicState {
+    pairs: Vec<SymbolicPair>,
+    unit: usize,
+    step: usize,
+    copy_mode: bool,
+    flipped: bool,
+    open_left: bool,
+    open_right: bool,
+    min_offset: i32,
+    max_offset: i32,
+    conditions: Vec<Condition>,
+    reads: BTreeSet<i32>,
+    writes: BTreeSet<i32>,
+    edits: Vec<SymbolicEdit>,
+    products: Vec<SymbolicStrand>,
+}
+
+enum Move {
+    Moved(SymbolicState),
+    End(SymbolicState),
+    Truncated(SymbolicState),
+}
+
+enum Advance {
+    Continue(SymbolicState),
+    Done(SymbolicState, Outcome),
+}
+
+/// Runs an enzyme against a strand whose bases are unknown apart from the binding
+/// base, mirroring the semantics of `Rewriter::rewrite`. Execution forks whenever
+/// the result depends on an unknown base or on where the strand ends, so each
+/// returned path describes a family of strands by the conditions it collected. Bases
+/// left of the binding site are never the binding base, as the enzyme would have bound
+/// there instead.
+///
+/// No more than `max_flank` bases are explored on either side of the binding site.
+pub struct SymbolicExecutor;
+
+impl SymbolicExecutor {
+    pub fn execute(enzyme: &Enzyme, max_flank: usize) -> Vec<SymbolicPath> {
+        let amino_acids: Vec<AminoAcid> = enzyme.iter_amino_acids().cloned().collect();
+        let binding_base = Self::get_binding_base(enzyme);
+
+        let init_state = SymbolicState {
+            pairs: vec![SymbolicPair {
+                bind: Some(SymbolicBase::original(0)),
+                comp: None,
+            }],
+            unit: 0,
+            step: 0,
+            copy_mode: false,
+            flipped: false,
+            open_left: true,
+            open_right: true,
+            min_offset: 0,
+            max_offset: 0,
+            conditions: Vec::new(),
+            reads: BTreeSet::new(),
+            writes: BTreeSet::new(),
+            edits: Vec::new(),
+            products: Vec::new(),
+        };
+
+        let mut paths = Vec::new();
+        let mut stack = vec![init_state];
+
+        while let Some(state) = stack.pop() {
+            if state.step >= amino_acids.len() {
+                paths.push(Self::finish(state, Outcome::Completed));
+                continue;
+            }
+
+            let amino_acid = amino_acids[state.step];
+            let advances = Self::advance(state, amino_acid, binding_base, max_flank);
+            for advance in advances.into_iter().rev() {
+                match advance {
+                    Advance::Continue(next_state) => stack.push(next_state),
+                    Advance::Done(final_state, outcome) => {
+                        paths.push(Self::finish(final_state, outcome))
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    pub fn get_binding_base(enzyme: &Enzyme) -> Base {
+        Folder::get_binding_affinity(Folder::fold(enzyme))
+    }
+
+    fn advance(
+        mut state: SymbolicState,
+        amino_acid: AminoAcid,
+        binding_base: Base,
+        max_flank: usize,
+    ) -> Vec<Advance> {
+        let step = state.step;
+
+        match amino_acid {
+            AminoAcid::Cut => {
+                if let Some(after) = state.pairs[state.unit].bind {
+                    state.edits.push(SymbolicEdit::Cut { after });
+                }
+                let cut_pairs = state.pairs.split_off(state.unit + 1);
+                let cut_strands =
+                    Self::strands_from_pairs(&cut_pairs, false, state.open_right, !state.flipped);
+                state.products.extend(cut_strands);
+                state.open_right = false;
+                state.step += 1;
+                vec![Advance::Continue(state)]
+            }
+            AminoAcid::Del => {
+                if let Some(base) = state.pairs[state.unit].bind.take() {
+                    state.edits.push(SymbolicEdit::Delete { base });
+                    if let Some(offset) = base.get_offset() {
+                        state.writes.insert(offset);
+                    }
+                }
+                Self::move_unit(state, -1, binding_base, max_flank)
+                    .into_iter()
+                    .map(|m| match m {
+                        Move::Moved(mut s) => {
+                            if s.pairs[s.unit].bind.is_none() {
+                                Advance::Done(s, Outcome::EndOfStrand { step })
+                            } else {
+                                s.step += 1;
+                                Advance::Continue(s)
+                            }
+                        }
+                        Move::End(s) => Advance::Done(s, Outcome::EndOfStrand { step }),
+                        Move::Truncated(s) => Advance::Done(s, Outcome::FlankLimit { step }),
+                    })
+                    .collect()
+            }
+            AminoAcid::Swi => {
+                let pair = &state.pairs[state.unit];
+                let at = match (pair.comp, pair.bind) {
+                    (Some(_), Some(bind)) => bind,
+                    _ => return vec![Advance::Done(state, Outcome::EmptyComplement { step })],
+                };
+                state.edits.push(SymbolicEdit::Switch { at });
+                for pair in &mut state.pairs {
+                    std::mem::swap(&mut pair.bind, &mut pair.comp);
+                }
+                state.pairs.reverse();
+                state.unit = state.pairs.len() - state.unit - 1;
+                std::mem::swap(&mut state.open_left, &mut state.open_right);
+                state.flipped = !state.flipped;
+                state.step += 1;
+                vec![Advance::Continue(state)]
+            }
+            AminoAcid::Mvr | AminoAcid::Mvl => {
+                let direction = Rewriter::amino_acid_to_direction(amino_acid).unwrap_or(0);
+                Self::move_unit(state, direction, binding_base, max_flank)
+                    .into_iter()
+                    .map(|m| match m {
+                        Move::Moved(mut s) => {
+                            if s.pairs[s.unit].bind.is_none() {
+                                return Advance::Done(s, Outcome::EndOfStrand { step });
+                            }
+                            if s.copy_mode {
+                                Self::add_comp(&mut s);
+                            }
+                            s.step += 1;
+                            Advance::Continue(s)
+                        }
+                        Move::End(s) => Advance::Done(s, Outcome::EndOfStrand { step }),
+                        Move::Truncated(s) => Advance::Done(s, Outcome::FlankLimit { step }),
+                    })
+                    .collect()
+            }
+            AminoAcid::Cop => {
+                state.copy_mode = true;
+                Self::add_comp(&mut state);
+                state.step += 1;
+                vec![Advance::Continue(state)]
+            }
+            AminoAcid::Off => {
+                state.copy_mode = false;
+                state.step += 1;
+                vec![Advance::Continue(state)]
+            }
+            AminoAcid::Ina | AminoAcid::Inc | AminoAcid::Ing | AminoAcid::Int => {
+                if let Some(base) = Rewriter::amino_acid_to_base(amino_acid) {
+                    if let Some(after) = state.pairs[state.unit].bind {
+                        state.edits.push(SymbolicEdit::Insert { base, after });
+                    }
+                    let bind = SymbolicBase::Inserted(base);
+                    state.pairs.insert(
+                        state.unit + 1,
+                        SymbolicPair {
+                            bind: Some(bind),
+                            comp: if state.copy_mode {
+                                Some(bind.get_complement())
+                            } else {
+                                None
+                            },
+                        },
+                    );
+                }
+                state.step += 1;
+                vec![Advance::Continue(state)]
+            }
+            AminoAcid::Rpy | AminoAcid::Rpu | AminoAcid::Lpy | AminoAcid::Lpu => {
+                let direction = Rewriter::amino_acid_to_direction(amino_acid).unwrap_or(0);
+                let base_type = Rewriter::amino_acid_to_base_type(amino_acid);
+                let mut advances = Vec::new();
+                for m in Self::move_unit(state, direction, binding_base, max_flank) {
+                    match m {
+                        Move::Moved(mut s) => {
+                            let bind = match s.pairs[s.unit].bind {
+                                Some(bind) => bind,
+                                None => {
+                                    advances.push(Advance::Done(s, Outcome::EndOfStrand { step }));
+                                    continue;
+                                }
+                            };
+                            if s.copy_mode {
+                                Self::add_comp(&mut s);
+                            }
+                            for (mut branch, found) in
+                                Self::test_type(s, bind, base_type, binding_base)
+                            {
+                                if found {
+                                    branch.step += 1;
+                                }
+                                advances.push(Advance::Continue(branch));
+                            }
+                        }
+                        Move::End(s) => {
+                            advances.push(Advance::Done(s, Outcome::EndOfStrand { step }))
+                        }
+                        Move::Truncated(s) => {
+                            advances.push(Advance::Done(s, Outcome::FlankLimit { step }))
+                        }
+                    }
+                }
+                advances
+            }
+        }
+    }
+
+    /// Move the unit one position, forking on whether the strand extends past the
+    /// bases explored so far.
+    fn move_unit(
+        mut state: SymbolicState,
+        direction: i32,
+        binding_base: Base,
+        max_flank: usize,
+    ) -> Vec<Move> {
+        let left = direction < 0;
+        if left && state.unit > 0 {
+            state.unit -= 1;
+            return vec![Move::Moved(state)];
+        }
+        if !left && state.unit + 1 < state.pairs.len() {
+            state.unit += 1;
+            return vec![Move::Moved(state)];
+        }
+
+        let open = if left {
+            state.open_left
+        } else {
+            state.open_right
+        };
+        // After a switch the unexplored original bases sit on the complement side,
+        // so the bound side is empty beyond the explored region either way
+        if !open || state.flipped {
+            return vec![Move::End(state)];
+        }
+
+        let offset = if left {
+            state.min_offset - 1
+        } else {
+            state.max_offset + 1
+        };
+        if offset.unsigned_abs() as usize > max_flank {
+            return vec![Move::Truncated(state)];
+        }
+
+        let mut ends = state.clone();
+        ends.conditions.push(Condition::EndsBefore { offset });
+        if left {
+            ends.open_left = false;
+        } else {
+            ends.open_right = false;
+        }
+
+        let mut extends = state;
+        extends.conditions.push(Condition::Extends { offset });
+        if left {
+            extends.conditions.push(Condition::IsNot {
+                offset,
+                base: binding_base,
+            });
+        }
+        let pair = SymbolicPair {
+            bind: Some(SymbolicBase::original(offset)),
+            comp: None,
+        };
+        if left {
+            extends.pairs.insert(0, pair);
+            extends.unit = 0;
+            extends.min_offset = offset;
+        } else {
+            extends.pairs.push(pair);
+            extends.unit = extends.pairs.len() - 1;
+            extends.max_offset = offset;
+        }
+
+        vec![Move::Moved(extends), Move::End(ends)]
+    }
+
+    /// Decide whether a base has the given type, forking when it is unknown and no
+    /// earlier condition already decided it.
+    fn test_type(
+        mut state: SymbolicState,
+        base: SymbolicBase,
+        base_type: BaseType,
+        binding_base: Base,
+    ) -> Vec<(SymbolicState, bool)> {
+        if let Some(known) = base.resolve(binding_base) {
+            let found = known.is_type(base_type);
+            return vec![(state, found)];
+        }
+
+        let offset = match base.get_offset() {
+            Some(offset) => offset,
+            None => return vec![(state, false)],
+        };
+        state.reads.insert(offset);
+        let required = base.get_original_type(base_type);
+
+        let existing = state.conditions.iter().find_map(|c| match c {
+            Condition::IsType {
+                offset: o,
+                base_type: t,
+            } if *o == offset => Some(*t),
+            _ => None,
+        });
+        if let Some(existing) = existing {
+            return vec![(state, existing == required)];
+        }
+
+        let other = match required {
+            BaseType::Purine => BaseType::Pyrimidine,
+            BaseType::Pyrimidine => BaseType::Purine,
+        };
+        let mut found = state.clone();
+        found.conditions.push(Condition::IsType {
+            offset,
+            base_type: required,
+        });
+        let mut not_found = state;
+        not_found.conditions.push(Condition::IsType {
+            offset,
+            base_type: other,
+        });
+        vec![(found, true), (not_found, false)]
+    }
+
+    fn add_comp(state: &mut SymbolicState) {
+        let pair = &mut state.pairs[state.unit];
+        if let Some(bind) = pair.bind {
+            pair.comp = Some(bind.get_complement());
+            state.edits.push(SymbolicEdit::Copy { base: bind });
+            if let Some(offset) = bind.get_offset() {
+                state.reads.insert(offset);
+                state.writes.insert(offset);
+            }
+        }
+    }
+
+    fn finish(mut state: SymbolicState, outcome: Outcome) -> SymbolicPath {
+        let strands = Self::strands_from_pairs(
+            &state.pairs,
+            state.open_left,
+            state.open_right,
+            !state.flipped,
+        );
+        state.products.extend(strands);
+
+        SymbolicPath {
+            conditions: state.conditions,
+            outcome,
+            reads: state.reads,
+            writes: state.writes,
+            edits: state.edits,
+            products: state.products,
+        }
+    }
+
+    /// Collect the strands in a set of pairs. The open ends belong to whichever side
+    /// holds the original strand.
+    fn strands_from_pairs(
+        pairs: &[SymbolicPair],
+        open_left: bool,
+        open_right: bool,
+        original_is_bind: bool,
+    ) -> Vec<SymbolicStrand> {
+        let bind_bases: Vec<Option<SymbolicBase>> = pairs.iter().map(|p| p.bind).collect();
+        let comp_bases: Vec<Option<SymbolicBase>> = pairs.iter().map(|p| p.comp).collect();
+
+        let mut strands = Self::strands_from_row(
+            &bind_bases,
+            open_left && original_is_bind,
+            open_right && original_is_bind,
+        );
+        let comp_strands = Self::strands_from_row(
+            &comp_bases,
+            open_left && !original_is_bind,
+            open_right && !original_is_bind,
+        );
+        strands.extend(comp_strands.iter().map(|s| s.reversed()));
+        strands
+    }
+
+    fn strands_from_row(
+        row: &[Option<SymbolicBase>],
+        open_left: bool,
+        open_right: bool,
+    ) -> Vec<SymbolicStrand> {
+        let mut strands = Vec::new();
+        let mut bases = Vec::new();
+        let mut open = open_left;
+
+        for base in row {
+            if let Some(base) = base {
+                bases.push(*base);
+            } else {
+                if !bases.is_empty() || open {
+                    strands.push(SymbolicStrand {
+                        bases: std::mem::take(&mut bases),
+                        open_left: open,
+                        open_right: false,
+                    });
+                }
+                open = false;
+            }
+        }
+
+        if !bases.is_empty() || open || open_right {
+            strands.push(SymbolicStrand {
+                bases,
+                open_left: open,
+                open_right,
+            });
+        }
+
+        strands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::SymbolicStrand;
+    use crate::typogenetics::Strand;
+
+    const BASES: [Base; 4] = [Base::A, Base::C, Base::G, Base::T];
+
+    /// Every strand of up to `max_len` bases.
+    fn all_strands(max_len: usize) -> Vec<Vec<Base>> {
+        let mut strands = vec![Vec::new()];
+        let mut last = vec![Vec::new()];
+        for _ in 0..max_len {
+            last = last
+                .iter()
+                .flat_map(|bases: &Vec<Base>| {
+                    BASES.iter().map(move |&base| {
+                        let mut longer = bases.clone();
+                        longer.push(base);
+                        longer
+                    })
+                })
+                .collect();
+            strands.extend(last.iter().cloned());
+        }
+        strands
+    }
+
+    fn base_at(bases: &[Base], site: usize, offset: i32) -> Option<Base> {
+        bases
+            .get(site.checked_add_signed(offset as isize)?)
+            .copied()
+    }
+
+    fn holds(condition: &Condition, bases: &[Base], site: usize) -> bool {
+        match *condition {
+            Condition::IsType { offset, base_type } => {
+                base_at(bases, site, offset).is_some_and(|base| base.is_type(base_type))
+            }
+            Condition::IsNot { offset, base } => {
+                base_at(bases, site, offset).is_some_and(|b| b != base)
+            }
+            Condition::Extends { offset } => base_at(bases, site, offset).is_some(),
+            Condition::EndsBefore { offset } => base_at(bases, site, offset).is_none(),
+        }
+    }
+
+    /// The concrete strand a symbolic product stands for, with its open ends filled by
+    /// the original bases the enzyme never reached.
+    fn concretize(
+        strand: &SymbolicStrand,
+        path: &SymbolicPath,
+        bases: &[Base],
+        site: usize,
+    ) -> String {
+        let explored = path.conditions.iter().filter_map(|c| match c {
+            Condition::Extends { offset } => Some(*offset),
+            _ => None,
+        });
+        let min_offset = explored.clone().min().unwrap_or(0).min(0);
+        let max_offset = explored.max().unwrap_or(0).max(0);
+        let start = site.checked_add_signed(min_offset as isize).unwrap();
+        let end = site.checked_add_signed(max_offset as isize).unwrap();
+
+        let mut concrete = Vec::new();
+        if strand.open_left {
+            concrete.extend_from_slice(&bases[..start]);
+        }
+        for base in &strand.bases {
+            concrete.push(match *base {
+                SymbolicBase::Original { offset, complement } => {
+                    let original = base_at(bases, site, offset).unwrap();
+                    if complement {
+                        original.get_complement()
+                    } else {
+                        original
+                    }
+                }
+                SymbolicBase::Inserted(base) => base,
+            });
+        }
+        if strand.open_right {
+            concrete.extend_from_slice(&bases[end + 1..]);
+        }
+        concrete.iter().map(|base| base.to_string()).collect()
+    }
+
+    /// Check that exactly one path covers each strand of up to `max_len` bases that
+    /// the enzyme binds to, and that its products are those of `Rewriter::rewrite`.
+    fn assert_matches_rewriter(enzyme_str: &str, max_len: usize) {
+        let enzyme = Enzyme::from_string(enzyme_str);
+        let binding_base = SymbolicExecutor::get_binding_base(&enzyme);
+        let paths = SymbolicExecutor::execute(&enzyme, max_len);
+
+        for bases in all_strands(max_len) {
+            let Some(site) = bases.iter().position(|&base| base == binding_base) else {
+                continue;
+            };
+            let matching: Vec<&SymbolicPath> = paths
+                .iter()
+                .filter(|path| path.conditions.iter().all(|c| holds(c, &bases, site)))
+                .collect();
+            let strand_str: String = bases.iter().map(|base| base.to_string()).collect();
+            assert_eq!(matching.len(), 1, "{} on {}", enzyme_str, strand_str);
+
+            let mut symbolic: Vec<String> = matching[0]
+                .products
+                .iter()
+                .map(|product| concretize(product, matching[0], &bases, site))
+                .filter(|product| !product.is_empty())
+                .collect();
+            symbolic.sort();
+            let mut concrete: Vec<String> =
+                Rewriter::rewrite(&enzyme, &Strand::from_string(&strand_str))
+                    .iter()
+                    .map(|product| product.to_string())
+                    .filter(|product| !product.is_empty())
+                    .collect();
+            concrete.sort();
+            assert_eq!(symbolic, concrete, "{} on {}", enzyme_str, strand_str);
+        }
+    }
+
+    #[test]
+    fn left_flank_never_holds_binding_base() {
+        let enzyme = Enzyme::from_string("mvl-mvl-cut");
+        let binding_base = SymbolicExecutor::get_binding_base(&enzyme);
+        for path in SymbolicExecutor::execute(&enzyme, 4) {
+            for condition in &path.conditions {
+                if let Condition::Extends { offset } = *condition {
+                    assert_eq!(
+                        offset >= 0,
+                        !path.conditions.contains(&Condition::IsNot {
+                            offset,
+                            base: binding_base
+                        })
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn searches_match_rewriter() {
+        assert_matches_rewriter("rpy-ing-swi", 6);
+        assert_matches_rewriter("lpu-cut", 6);
+        assert_matches_rewriter("cop-rpu-lpy", 6);
+    }
+
+    #[test]
+    fn edits_match_rewriter() {
+        assert_matches_rewriter("del-cop-mvr-ina", 6);
+        assert_matches_rewriter("lpy-del-ina-rpy-swi", 6);
+        assert_matches_rewriter("cop-mvl-swi-cut-inc", 6);
+        assert_matches_rewriter("off-del-cop", 6);
+    }
+}
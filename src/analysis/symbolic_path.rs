@@ -0,0 +1,55 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+};
+
+use crate::analysis::{Condition, Outcome, SymbolicBase, SymbolicEdit, SymbolicStrand};
+
+/// One way an enzyme can execute over the family of strands described by its
+/// conditions. Reads and writes are offsets of original bases from the binding site.
+#[derive(Debug, Clone)]
+pub struct SymbolicPath {
+    pub conditions: Vec<Condition>,
+    pub outcome: Outcome,
+    pub reads: BTreeSet<i32>,
+    pub writes: BTreeSet<i32>,
+    pub edits: Vec<SymbolicEdit>,
+    pub products: Vec<SymbolicStrand>,
+}
+
+impl Display for SymbolicPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |items: Vec<String>| {
+            if items.is_empty() {
+                String::from("none")
+            } else {
+                items.join(", ")
+            }
+        };
+        let offsets_to_strings = |offsets: &BTreeSet<i32>| {
+            offsets
+                .iter()
+                .map(|&o| SymbolicBase::original(o).to_string())
+                .collect::<Vec<String>>()
+        };
+
+        writeln!(f, "outcome: {}", self.outcome)?;
+        writeln!(
+            f,
+            "conditions: {}",
+            join(self.conditions.iter().map(|c| c.to_string()).collect())
+        )?;
+        writeln!(f, "reads: {}", join(offsets_to_strings(&self.reads)))?;
+        writeln!(f, "writes: {}", join(offsets_to_strings(&self.writes)))?;
+        writeln!(
+            f,
+            "edits: {}",
+            join(self.edits.iter().map(|e| e.to_string()).collect())
+        )?;
+        write!(
+            f,
+            "products: {}",
+            join(self.products.iter().map(|s| format!("[{}]", s)).collect())
+        )
+    }
+}
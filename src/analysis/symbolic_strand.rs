@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::analysis::SymbolicBase;
+
+/// A strand produced by symbolic execution. An open end stands for zero or more
+/// unknown original bases that the enzyme never reached.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SymbolicStrand {
+    pub bases: Vec<SymbolicBase>,
+    pub open_left: bool,
+    pub open_right: bool,
+}
+
+impl Display for SymbolicStrand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut s = String::new();
+        if self.open_left {
+            s.push('…');
+        }
+        let bases = self
+            .bases
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        if self.open_left && !bases.is_empty() {
+            s.push(' ');
+        }
+        s.push_str(&bases);
+        if self.open_right {
+            if !bases.is_empty() {
+                s.push(' ');
+            }
+            s.push('…');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+impl SymbolicStrand {
+    pub fn reversed(&self) -> SymbolicStrand {
+        SymbolicStrand {
+            bases: self.bases.iter().cloned().rev().collect(),
+            open_left: self.open_right,
+            open_right: self.open_left,
+        }
+    }
+}
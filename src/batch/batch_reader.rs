@@ -0,0 +1,58 @@
+use std::io::BufRead;
+
+use crate::batch::BatchRecord;
+
+/// Reads batch input with one record per line. Each line holds a fixed number of
+/// whitespace separated fields, optionally preceded by an ID. Lines without an ID
+/// are identified by their line number. Everything after a `#` is a comment.
+pub struct BatchReader;
+
+impl BatchReader {
+    pub fn read(
+        input: impl BufRead,
+        n_fields: usize,
+    ) -> impl Iterator<Item = Result<BatchRecord, String>> {
+        input
+            .lines()
+            .enumerate()
+            .filter_map(move |(i, line)| match line {
+                Ok(line) => Self::parse_line(&line, i + 1, n_fields).transpose(),
+                Err(e) => Some(Err(format!("line {}: {}", i + 1, e))),
+            })
+    }
+
+    pub fn parse_line(
+        line: &str,
+        line_number: usize,
+        n_fields: usize,
+    ) -> Result<Option<BatchRecord>, String> {
+        let content = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => line,
+        };
+        let tokens: Vec<&str> = content.split_whitespace().collect();
+
+        if tokens.is_empty() {
+            Ok(None)
+        } else if tokens.len() == n_fields {
+            Ok(Some(BatchRecord {
+                id: line_number.to_string(),
+                line_number,
+                fields: tokens.iter().map(|t| t.to_string()).collect(),
+            }))
+        } else if tokens.len() == n_fields + 1 {
+            Ok(Some(BatchRecord {
+                id: tokens[0].to_string(),
+                line_number,
+                fields: tokens[1..].iter().map(|t| t.to_string()).collect(),
+            }))
+        } else {
+            Err(format!(
+                "line {}: expected {} fields with an optional ID but found {}",
+                line_number,
+                n_fields,
+                tokens.len()
+            ))
+        }
+    }
+}
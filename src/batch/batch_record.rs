@@ -0,0 +1,7 @@
+/// One input line of a batch, split into whitespace separated fields.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BatchRecord {
+    pub id: String,
+    pub line_number: usize,
+    pub fields: Vec<String>,
+}
@@ -0,0 +1,135 @@
+use std::io::{BufRead, Write};
+
+use serde_json::{json, Value};
+
+use crate::batch::{BatchReader, BatchRecord, BatchWriter, OutputFormat};
+use crate::fasta::FastaReader;
+use crate::query::Filter;
+use crate::typogenetics::{Enzyme, Rewriter, Strand, Translator};
+
+/// Runs translations and rewrites over batch input, which is either FASTA or lines of
+/// whitespace separated fields. Records that fail to parse are logged and skipped,
+/// and the number of failures is returned.
+pub struct BatchRunner;
+
+impl BatchRunner {
+    /// Translate records of the form `[ID] STRAND`, skipping strands that do not match
+    /// the filter.
+    pub fn translate(
+        input: impl BufRead,
+        filter: Option<&Filter>,
+        writer: &mut BatchWriter<impl Write>,
+    ) -> std::io::Result<usize> {
+        if writer.get_format() == OutputFormat::Fasta {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Translations are enzymes, which cannot be written as FASTA",
+            ));
+        }
+
+        Self::run(Self::read_records(input, 1)?, writer, |record, writer| {
+            let strand = Strand::try_from_string(&record.fields[0])?;
+            if filter.is_some_and(|filter| !filter.matches(&strand)) {
+                return Ok(Ok(()));
+            }
+            let enzymes = Translator::translate(&strand);
+            Ok(writer.write_row(&[
+                ("id", json!(record.id)),
+                ("strand", json!(strand.to_string())),
+                ("enzymes", Self::to_json_list(&enzymes)),
+            ]))
+        })
+    }
+
+    /// Rewrite records of the form `[ID] ENZYME STRAND`, or `[ID] STRAND` when a single
+    /// enzyme is applied to every strand. Only products matching the filter are kept.
+    pub fn rewrite(
+        input: impl BufRead,
+        enzyme: Option<&Enzyme>,
+        filter: Option<&Filter>,
+        writer: &mut BatchWriter<impl Write>,
+    ) -> std::io::Result<usize> {
+        let n_fields = if enzyme.is_some() { 1 } else { 2 };
+        Self::run(
+            Self::read_records(input, n_fields)?,
+            writer,
+            |record, writer| {
+                let (enzyme, strand_str) = match enzyme {
+                    Some(enzyme) => (enzyme.clone(), &record.fields[0]),
+                    None => (
+                        Enzyme::try_from_string(&record.fields[0])?,
+                        &record.fields[1],
+                    ),
+                };
+                let strand = Strand::try_from_string(strand_str)?;
+                let mut products = Rewriter::rewrite(&enzyme, &strand);
+                if let Some(filter) = filter {
+                    products.retain(|product| filter.matches(product));
+                }
+                if writer.get_format() == OutputFormat::Fasta {
+                    return Ok(writer.write_strands(&record.id, &products));
+                }
+                Ok(writer.write_row(&[
+                    ("id", json!(record.id)),
+                    ("enzyme", json!(enzyme.to_string())),
+                    ("strand", json!(strand.to_string())),
+                    ("products", Self::to_json_list(&products)),
+                ]))
+            },
+        )
+    }
+
+    /// Read records from FASTA or field based input. FASTA records can only stand in
+    /// for records with a single strand field.
+    pub fn read_records<'a>(
+        mut input: impl BufRead + 'a,
+        n_fields: usize,
+    ) -> std::io::Result<Box<dyn Iterator<Item = Result<BatchRecord, String>> + 'a>> {
+        if !FastaReader::is_fasta(&mut input)? {
+            return Ok(Box::new(BatchReader::read(input, n_fields)));
+        }
+
+        if n_fields != 1 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "FASTA input only holds strands, give an enzyme to apply to them",
+            ));
+        }
+        let records = FastaReader::read(input)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Box::new(records.into_iter().enumerate().map(
+            |(i, record)| {
+                Ok(BatchRecord {
+                    id: record.id,
+                    line_number: i + 1,
+                    fields: vec![record.strand.to_string()],
+                })
+            },
+        )))
+    }
+
+    fn run<W: Write>(
+        records: impl Iterator<Item = Result<BatchRecord, String>>,
+        writer: &mut BatchWriter<W>,
+        process: impl Fn(&BatchRecord, &mut BatchWriter<W>) -> Result<std::io::Result<()>, String>,
+    ) -> std::io::Result<usize> {
+        let mut n_failed = 0;
+        for record in records {
+            let result = record.and_then(|record| {
+                process(&record, writer).map_err(|e| format!("line {}: {}", record.line_number, e))
+            });
+            match result {
+                Ok(written) => written?,
+                Err(message) => {
+                    log::error!("{}", message);
+                    n_failed += 1;
+                }
+            }
+        }
+        Ok(n_failed)
+    }
+
+    fn to_json_list<T: ToString>(items: &[T]) -> Value {
+        Value::Array(items.iter().map(|i| json!(i.to_string())).collect())
+    }
+}
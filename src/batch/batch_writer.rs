@@ -0,0 +1,84 @@
+use std::io::Write;
+
+use serde_json::{Map, Value};
+
+use crate::batch::OutputFormat;
+use crate::fasta::{FastaRecord, FastaWriter};
+use crate::typogenetics::Strand;
+
+/// Writes batch results as rows of named columns. TSV output starts with a header
+/// row and joins list values with commas, JSONL output writes one object per row.
+/// FASTA output holds strands only, which are written with `write_strands`.
+pub struct BatchWriter<W: Write> {
+    output: W,
+    format: OutputFormat,
+    wrote_header: bool,
+}
+
+impl<W: Write> BatchWriter<W> {
+    pub fn new(output: W, format: OutputFormat) -> BatchWriter<W> {
+        BatchWriter {
+            output,
+            format,
+            wrote_header: false,
+        }
+    }
+
+    pub fn get_format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Write strands as FASTA records with IDs numbered from the given ID.
+    pub fn write_strands(&mut self, id: &str, strands: &[Strand]) -> std::io::Result<()> {
+        for (i, strand) in strands.iter().enumerate() {
+            let record = FastaRecord {
+                id: format!("{}.{}", id, i + 1),
+                description: String::new(),
+                strand: strand.clone(),
+            };
+            FastaWriter::write_record(&mut self.output, &record)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_row(&mut self, columns: &[(&str, Value)]) -> std::io::Result<()> {
+        match self.format {
+            OutputFormat::Tsv => {
+                if !self.wrote_header {
+                    let names: Vec<&str> = columns.iter().map(|(name, _)| *name).collect();
+                    writeln!(self.output, "{}", names.join("\t"))?;
+                    self.wrote_header = true;
+                }
+                let values: Vec<String> = columns
+                    .iter()
+                    .map(|(_, value)| Self::to_tsv_field(value))
+                    .collect();
+                writeln!(self.output, "{}", values.join("\t"))
+            }
+            OutputFormat::Fasta => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Rows of results cannot be written as FASTA",
+            )),
+            OutputFormat::Jsonl => {
+                let object: Map<String, Value> = columns
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect();
+                writeln!(self.output, "{}", Value::Object(object))
+            }
+        }
+    }
+
+    fn to_tsv_field(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            Value::Array(values) => values
+                .iter()
+                .map(Self::to_tsv_field)
+                .collect::<Vec<String>>()
+                .join(","),
+            Value::Null => String::new(),
+            other => other.to_string(),
+        }
+    }
+}
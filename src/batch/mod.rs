@@ -0,0 +1,11 @@
+mod batch_reader;
+mod batch_record;
+mod batch_runner;
+mod batch_writer;
+mod output_format;
+
+pub use batch_reader::BatchReader;
+pub use batch_record::BatchRecord;
+pub use batch_runner::BatchRunner;
+pub use batch_writer::BatchWriter;
+pub use output_format::OutputFormat;
@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OutputFormat {
+    Tsv,
+    Jsonl,
+    Fasta,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format_str: &str) -> Result<OutputFormat, String> {
+        match format_str {
+            "tsv" => Ok(OutputFormat::Tsv),
+            "jsonl" => Ok(OutputFormat::Jsonl),
+            "fasta" => Ok(OutputFormat::Fasta),
+            _ => Err(format!(
+                "Invalid output format '{}', expected tsv, jsonl or fasta",
+                format_str
+            )),
+        }
+    }
+}
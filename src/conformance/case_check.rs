@@ -0,0 +1,15 @@
+use crate::typogenetics::{Enzyme, Strand};
+
+/// What a conformance case expects of its strand.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CaseCheck {
+    /// The strand translates into these enzymes.
+    Translate { enzymes: Vec<Enzyme> },
+    /// The enzyme rewrites the strand into these products, in order, optionally passing
+    /// through these states as formatted by `ConformanceRunner::format_state`.
+    Rewrite {
+        enzyme: Enzyme,
+        products: Vec<Strand>,
+        trace: Option<Vec<String>>,
+    },
+}
@@ -0,0 +1,19 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// A conformance case whose actual output differs from the expected output.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CaseFailure {
+    pub name: String,
+    pub source: String,
+    pub diffs: Vec<String>,
+}
+
+impl Display for CaseFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "FAIL {} ({})", self.name, self.source)?;
+        for diff in &self.diffs {
+            write!(f, "\n  {}", diff)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::conformance::CaseCheck;
+use crate::typogenetics::Strand;
+
+/// A named golden case read from a corpus file. `source` is the file and line the case
+/// starts on.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConformanceCase {
+    pub name: String,
+    pub source: String,
+    pub strand: Strand,
+    pub check: CaseCheck,
+}
@@ -0,0 +1,175 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::conformance::{CaseCheck, ConformanceCase};
+use crate::typogenetics::{Enzyme, Strand};
+
+struct PendingCase {
+    name: String,
+    source: String,
+    strand: Option<Strand>,
+    enzyme: Option<Enzyme>,
+    enzymes: Option<Vec<Enzyme>>,
+    products: Option<Vec<Strand>>,
+    trace: Option<Vec<String>>,
+}
+
+/// Reads conformance cases. A case is a block of `KEY VALUE` lines starting with
+/// `case NAME`. Translation cases give a `strand` and the `enzymes` it translates into.
+/// Rewrite cases give an `enzyme`, a `strand`, the `products` and optionally one
+/// `trace` line per rewrite state. Lists are separated by spaces and may be empty.
+/// Blank lines and lines starting with `#` are ignored.
+///
+/// ```text
+/// case cut at the end of the strand
+/// enzyme cut
+/// strand CAG
+/// products CAG
+/// trace CAG --- 0 off
+/// ```
+pub struct ConformanceReader;
+
+impl ConformanceReader {
+    /// File extension of corpus files in a directory.
+    pub const EXTENSION: &'static str = "cases";
+
+    pub fn read(input: impl BufRead, path: &str) -> Result<Vec<ConformanceCase>, String> {
+        let mut cases = Vec::new();
+        let mut current: Option<PendingCase> = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.map_err(|e| format!("{}:{}: {}", path, line_number, e))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let value = value.trim();
+            if key == "case" {
+                if let Some(case) = current.take() {
+                    cases.push(Self::finish_case(case)?);
+                }
+                if value.is_empty() {
+                    return Err(format!("{}:{}: Case is missing a name", path, line_number));
+                }
+                current = Some(PendingCase {
+                    name: String::from(value),
+                    source: format!("{}:{}", path, line_number),
+                    strand: None,
+                    enzyme: None,
+                    enzymes: None,
+                    products: None,
+                    trace: None,
+                });
+                continue;
+            }
+
+            let Some(case) = current.as_mut() else {
+                return Err(format!(
+                    "{}:{}: Expected a case line before '{}'",
+                    path, line_number, key
+                ));
+            };
+            Self::set_field(case, key, value)
+                .map_err(|e| format!("{}:{}: {}", path, line_number, e))?;
+        }
+
+        if let Some(case) = current.take() {
+            cases.push(Self::finish_case(case)?);
+        }
+        Ok(cases)
+    }
+
+    /// Read a corpus file, or every corpus file in a directory in name order.
+    pub fn read_path(path: &Path) -> Result<Vec<ConformanceCase>, String> {
+        if !path.is_dir() {
+            let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            return Self::read(BufReader::new(file), &path.display().to_string());
+        }
+
+        let entries = std::fs::read_dir(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry_path = entry.map_err(|e| e.to_string())?.path();
+            if entry_path
+                .extension()
+                .is_some_and(|ext| ext == Self::EXTENSION)
+            {
+                paths.push(entry_path);
+            }
+        }
+        paths.sort();
+
+        let mut cases = Vec::new();
+        for path in paths {
+            cases.extend(Self::read_path(&path)?);
+        }
+        Ok(cases)
+    }
+
+    fn set_field(case: &mut PendingCase, key: &str, value: &str) -> Result<(), String> {
+        let is_duplicate = match key {
+            "strand" => case
+                .strand
+                .replace(Strand::try_from_string(value)?)
+                .is_some(),
+            "enzyme" => case
+                .enzyme
+                .replace(Enzyme::try_from_string(value)?)
+                .is_some(),
+            "enzymes" => {
+                let enzymes = value
+                    .split_whitespace()
+                    .map(Enzyme::try_from_string)
+                    .collect::<Result<_, _>>()?;
+                case.enzymes.replace(enzymes).is_some()
+            }
+            "products" => {
+                let products = value
+                    .split_whitespace()
+                    .map(Strand::try_from_string)
+                    .collect::<Result<_, _>>()?;
+                case.products.replace(products).is_some()
+            }
+            "trace" => {
+                let state = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                case.trace.get_or_insert_with(Vec::new).push(state);
+                false
+            }
+            _ => return Err(format!("Unknown key '{}'", key)),
+        };
+        if is_duplicate {
+            return Err(format!("Duplicate key '{}'", key));
+        }
+        Ok(())
+    }
+
+    fn finish_case(case: PendingCase) -> Result<ConformanceCase, String> {
+        let Some(strand) = case.strand else {
+            return Err(format!("{}: Case is missing a strand", case.source));
+        };
+        let check = match (case.enzyme, case.enzymes, case.products) {
+            (None, Some(enzymes), None) if case.trace.is_none() => CaseCheck::Translate { enzymes },
+            (Some(enzyme), None, Some(products)) => CaseCheck::Rewrite {
+                enzyme,
+                products,
+                trace: case.trace,
+            },
+            _ => {
+                return Err(format!(
+                    "{}: Case needs either enzymes, or an enzyme and products",
+                    case.source
+                ))
+            }
+        };
+        Ok(ConformanceCase {
+            name: case.name,
+            source: case.source,
+            strand,
+            check,
+        })
+    }
+}
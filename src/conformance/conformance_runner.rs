@@ -0,0 +1,113 @@
+use crate::conformance::{CaseCheck, CaseFailure, ConformanceCase, VerifyReport};
+use crate::typogenetics::{Base, BasePair, HaltReason, RewriteState, Rewriter, Translator};
+
+/// Checks conformance cases against `Translator` and `Rewriter`.
+pub struct ConformanceRunner;
+
+impl ConformanceRunner {
+    pub fn verify(cases: &[ConformanceCase]) -> VerifyReport {
+        let failures = cases
+            .iter()
+            .filter_map(|case| {
+                let diffs = Self::check(case);
+                if diffs.is_empty() {
+                    None
+                } else {
+                    Some(CaseFailure {
+                        name: case.name.clone(),
+                        source: case.source.clone(),
+                        diffs,
+                    })
+                }
+            })
+            .collect();
+        VerifyReport {
+            n_cases: cases.len(),
+            failures,
+        }
+    }
+
+    /// Differences between the expected and actual output of a case, empty if it passes.
+    pub fn check(case: &ConformanceCase) -> Vec<String> {
+        let mut diffs = Vec::new();
+        match &case.check {
+            CaseCheck::Translate { enzymes } => {
+                let actual = Translator::translate(&case.strand);
+                Self::diff_list("enzymes", enzymes, &actual, &mut diffs);
+            }
+            CaseCheck::Rewrite {
+                enzyme,
+                products,
+                trace,
+            } => {
+                let actual = Rewriter::rewrite(enzyme, &case.strand);
+                Self::diff_list("products", products, &actual, &mut diffs);
+
+                if let Some(trace) = trace {
+                    let actual: Vec<String> = Rewriter::trace(enzyme, &case.strand)
+                        .iter()
+                        .map(Self::format_state)
+                        .collect();
+                    for (i, (expected, actual)) in trace.iter().zip(&actual).enumerate() {
+                        if expected != actual {
+                            diffs.push(format!(
+                                "trace state {}: expected '{}', got '{}'",
+                                i, expected, actual
+                            ));
+                        }
+                    }
+                    if trace.len() != actual.len() {
+                        diffs.push(format!(
+                            "trace: expected {} states, got {}",
+                            trace.len(),
+                            actual.len()
+                        ));
+                    }
+                }
+            }
+        }
+        diffs
+    }
+
+    /// A rewrite state on one line: the bound strand and its complement, with `-` for
+    /// missing bases, the unit position, `on` or `off` for copy mode, and the halt
+    /// reason once the rewrite has halted.
+    pub fn format_state(state: &RewriteState) -> String {
+        let row = |base: fn(&BasePair) -> Option<Base>| -> String {
+            state
+                .pairs
+                .iter()
+                .map(|pair| base(pair).map_or(String::from("-"), |b| b.to_string()))
+                .collect()
+        };
+        let mut line = format!(
+            "{} {} {} {}",
+            row(|pair| pair.bind),
+            row(|pair| pair.comp),
+            state.unit,
+            if state.copy_mode { "on" } else { "off" }
+        );
+        match state.halt {
+            Some(HaltReason::EndOfStrand) => line.push_str(" end-of-strand"),
+            Some(HaltReason::EmptyComplement) => line.push_str(" empty-complement"),
+            None => {}
+        }
+        line
+    }
+
+    fn diff_list<T: ToString>(name: &str, expected: &[T], actual: &[T], diffs: &mut Vec<String>) {
+        let expected = Self::join(expected);
+        let actual = Self::join(actual);
+        if expected != actual {
+            diffs.push(format!(
+                "{}: expected '{}', got '{}'",
+                name, expected, actual
+            ));
+        }
+    }
+
+    fn join<T: ToString>(items: &[T]) -> String {
+        let items: Vec<String> = items.iter().map(|i| i.to_string()).collect();
+        items.join(" ")
+    }
+}
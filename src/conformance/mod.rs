@@ -0,0 +1,13 @@
+mod case_check;
+mod case_failure;
+mod conformance_case;
+mod conformance_reader;
+mod conformance_runner;
+mod verify_report;
+
+pub use case_check::CaseCheck;
+pub use case_failure::CaseFailure;
+pub use conformance_case::ConformanceCase;
+pub use conformance_reader::ConformanceReader;
+pub use conformance_runner::ConformanceRunner;
+pub use verify_report::VerifyReport;
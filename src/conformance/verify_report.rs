@@ -0,0 +1,31 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::conformance::CaseFailure;
+
+/// The outcome of checking a corpus of conformance cases.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VerifyReport {
+    pub n_cases: usize,
+    pub failures: Vec<CaseFailure>,
+}
+
+impl VerifyReport {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl Display for VerifyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for failure in &self.failures {
+            writeln!(f, "{}", failure)?;
+        }
+        write!(
+            f,
+            "{} cases, {} passed, {} failed",
+            self.n_cases,
+            self.n_cases - self.failures.len(),
+            self.failures.len()
+        )
+    }
+}
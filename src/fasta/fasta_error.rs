@@ -0,0 +1,23 @@
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FastaError {
+    pub line_number: usize,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Display for FastaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.column {
+            Some(column) => write!(
+                f,
+                "line {}, column {}: {}",
+                self.line_number, column, self.message
+            ),
+            None => write!(f, "line {}: {}", self.line_number, self.message),
+        }
+    }
+}
+
+impl std::error::Error for FastaError {}
@@ -0,0 +1,178 @@
+use std::io::BufRead;
+
+use crate::fasta::{FastaError, FastaRecord};
+use crate::typogenetics::{Base, Strand};
+
+struct PendingRecord {
+    id: String,
+    description: String,
+    line_number: usize,
+    bases: Vec<Base>,
+}
+
+/// Reads strands in FASTA format. Sequences may span several lines, lowercase bases
+/// are accepted and lines starting with `;` are comments.
+pub struct FastaReader;
+
+impl FastaReader {
+    pub fn read(input: impl BufRead) -> Result<Vec<FastaRecord>, FastaError> {
+        let mut records = Vec::new();
+        let mut current: Option<PendingRecord> = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.map_err(|e| FastaError {
+                line_number,
+                column: None,
+                message: e.to_string(),
+            })?;
+            let trimmed = line.trim_end();
+
+            if let Some(header) = trimmed.strip_prefix('>') {
+                if let Some(record) = current.take() {
+                    records.push(Self::finish_record(record)?);
+                }
+                let header = header.trim();
+                let (id, description) = match header.split_once(char::is_whitespace) {
+                    Some((id, description)) => (id, description.trim()),
+                    None => (header, ""),
+                };
+                if id.is_empty() {
+                    return Err(FastaError {
+                        line_number,
+                        column: Some(2),
+                        message: String::from("Header is missing a sequence ID"),
+                    });
+                }
+                current = Some(PendingRecord {
+                    id: String::from(id),
+                    description: String::from(description),
+                    line_number,
+                    bases: Vec::new(),
+                });
+            } else if trimmed.starts_with(';') || trimmed.trim().is_empty() {
+                continue;
+            } else if let Some(record) = current.as_mut() {
+                for (column, c) in trimmed.chars().enumerate() {
+                    if c.is_whitespace() {
+                        continue;
+                    }
+                    match Base::try_from_string(&c.to_ascii_uppercase().to_string()) {
+                        Some(base) => record.bases.push(base),
+                        None => {
+                            return Err(FastaError {
+                                line_number,
+                                column: Some(column + 1),
+                                message: format!(
+                                "Invalid base '{}' in sequence '{}', expected one of A, C, G, T",
+                                c, record.id
+                            ),
+                            })
+                        }
+                    }
+                }
+            } else {
+                return Err(FastaError {
+                    line_number,
+                    column: None,
+                    message: String::from(
+                        "Sequence data before the first header, headers start with '>'",
+                    ),
+                });
+            }
+        }
+
+        if let Some(record) = current.take() {
+            records.push(Self::finish_record(record)?);
+        }
+        Ok(records)
+    }
+
+    pub fn read_file(path: &str) -> Result<Vec<FastaRecord>, String> {
+        let file = std::fs::File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+        Self::read(std::io::BufReader::new(file)).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    /// Whether buffered input looks like FASTA, which is decided by its first
+    /// non-whitespace character being `>`. Nothing is consumed from the input.
+    pub fn is_fasta(input: &mut impl BufRead) -> std::io::Result<bool> {
+        let buffer = input.fill_buf()?;
+        Ok(buffer
+            .iter()
+            .find(|b| !b.is_ascii_whitespace())
+            .is_some_and(|&b| b == b'>'))
+    }
+
+    fn finish_record(record: PendingRecord) -> Result<FastaRecord, FastaError> {
+        if record.bases.is_empty() {
+            return Err(FastaError {
+                line_number: record.line_number,
+                column: None,
+                message: format!("Sequence '{}' has no bases", record.id),
+            });
+        }
+        Ok(FastaRecord {
+            id: record.id,
+            description: record.description,
+            strand: Strand::new(record.bases),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_error(input: &str) -> FastaError {
+        FastaReader::read(input.as_bytes()).unwrap_err()
+    }
+
+    #[test]
+    fn reads_multiline_lowercase_records() {
+        let records =
+            FastaReader::read("; soup\n>s1 first\nATAG\ncgaa\n\n>s2\nTT\n".as_bytes()).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, "s1");
+        assert_eq!(records[0].description, "first");
+        assert_eq!(records[0].strand, Strand::from_string("ATAGCGAA"));
+        assert_eq!(records[1].strand, Strand::from_string("TT"));
+    }
+
+    #[test]
+    fn rejects_invalid_base() {
+        let error = read_error(">s1\nATXG\n");
+
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.column, Some(3));
+        assert_eq!(
+            error.message,
+            "Invalid base 'X' in sequence 's1', expected one of A, C, G, T"
+        );
+    }
+
+    #[test]
+    fn rejects_header_without_id() {
+        let error = read_error("> \nATAG\n");
+
+        assert_eq!(error.line_number, 1);
+        assert_eq!(error.column, Some(2));
+        assert_eq!(error.message, "Header is missing a sequence ID");
+    }
+
+    #[test]
+    fn rejects_record_without_bases() {
+        let error = read_error(">s1\n>s2\nATAG\n");
+
+        assert_eq!(error.line_number, 1);
+        assert_eq!(error.message, "Sequence 's1' has no bases");
+    }
+
+    #[test]
+    fn rejects_sequence_before_header() {
+        let error = read_error("ATAG\n>s1\nATAG\n");
+
+        assert_eq!(error.line_number, 1);
+        assert_eq!(error.column, None);
+    }
+}
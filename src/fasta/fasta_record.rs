@@ -0,0 +1,10 @@
+use crate::typogenetics::Strand;
+
+/// A named strand. The ID is the first word of a FASTA header and the description is
+/// the rest of the header.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FastaRecord {
+    pub id: String,
+    pub description: String,
+    pub strand: Strand,
+}
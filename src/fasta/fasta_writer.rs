@@ -0,0 +1,31 @@
+use std::io::Write;
+
+use crate::fasta::FastaRecord;
+
+/// Writes strands in FASTA format, wrapping sequences at a fixed line width.
+pub struct FastaWriter;
+
+impl FastaWriter {
+    pub const LINE_WIDTH: usize = 60;
+
+    pub fn write(output: &mut impl Write, records: &[FastaRecord]) -> std::io::Result<()> {
+        for record in records {
+            Self::write_record(output, record)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_record(output: &mut impl Write, record: &FastaRecord) -> std::io::Result<()> {
+        if record.description.is_empty() {
+            writeln!(output, ">{}", record.id)?;
+        } else {
+            writeln!(output, ">{} {}", record.id, record.description)?;
+        }
+        let sequence = record.strand.to_string();
+        for start in (0..sequence.len()).step_by(Self::LINE_WIDTH) {
+            let end = (start + Self::LINE_WIDTH).min(sequence.len());
+            writeln!(output, "{}", &sequence[start..end])?;
+        }
+        Ok(())
+    }
+}
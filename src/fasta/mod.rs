@@ -0,0 +1,9 @@
+mod fasta_error;
+mod fasta_reader;
+mod fasta_record;
+mod fasta_writer;
+
+pub use fasta_error::FastaError;
+pub use fasta_reader::FastaReader;
+pub use fasta_record::FastaRecord;
+pub use fasta_writer::FastaWriter;
@@ -0,0 +1,23 @@
+//! A C API over translation, rewriting, folding and simulation. Strands, enzymes, lists
+//! and simulations are opaque handles created by the library and released with their
+//! `_free` function. Fallible functions return a `TgStatus` and write their result
+//! through an out pointer, which is left untouched on failure.
+
+mod tg_enzyme;
+mod tg_enzyme_list;
+mod tg_fold;
+mod tg_run_stats;
+mod tg_simulation;
+mod tg_status;
+mod tg_strand;
+mod tg_strand_list;
+mod tg_string;
+
+pub use tg_enzyme::TgEnzyme;
+pub use tg_enzyme_list::TgEnzymeList;
+pub use tg_fold::TgFold;
+pub use tg_run_stats::TgRunStats;
+pub use tg_simulation::TgSimulation;
+pub use tg_status::TgStatus;
+pub use tg_strand::TgStrand;
+pub use tg_strand_list::TgStrandList;
@@ -0,0 +1,94 @@
+use std::ffi::c_char;
+
+use crate::ffi::tg_string::{read_str, to_c_string, write_handle};
+use crate::ffi::{TgFold, TgStatus, TgStrand, TgStrandList};
+use crate::typogenetics::{Enzyme, Rewriter};
+
+/// Opaque handle to an enzyme.
+pub struct TgEnzyme(pub(crate) Enzyme);
+
+/// Parse an enzyme of amino acids separated by '-', such as "cop-mvr-cut".
+///
+/// # Safety
+///
+/// `amino_acids` must be null or a NUL terminated string, and `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_new(
+    amino_acids: *const c_char,
+    out: *mut *mut TgEnzyme,
+) -> TgStatus {
+    let amino_acids = match read_str(amino_acids) {
+        Ok(amino_acids) => amino_acids,
+        Err(status) => return status,
+    };
+    match Enzyme::try_from_string(amino_acids) {
+        Ok(enzyme) => write_handle(out, TgEnzyme(enzyme)),
+        Err(_) => TgStatus::InvalidEnzyme,
+    }
+}
+
+/// The amino acids of the enzyme, to be released with `tg_string_free`, or null for
+/// null.
+///
+/// # Safety
+///
+/// `enzyme` must be null or a live enzyme handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_to_string(enzyme: *const TgEnzyme) -> *mut c_char {
+    match enzyme.as_ref() {
+        Some(enzyme) => to_c_string(enzyme.0.to_string()),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Fold the enzyme, writing its final orientation and the base it binds to.
+///
+/// # Safety
+///
+/// `enzyme` must be null or a live enzyme handle, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_fold(enzyme: *const TgEnzyme, out: *mut TgFold) -> TgStatus {
+    let (Some(enzyme), false) = (enzyme.as_ref(), out.is_null()) else {
+        return TgStatus::NullPointer;
+    };
+    TgStatus::guard(|| {
+        *out = TgFold::from_enzyme(&enzyme.0);
+        TgStatus::Ok
+    })
+}
+
+/// Rewrite a strand with the enzyme, writing the products to a new list.
+///
+/// # Safety
+///
+/// `enzyme` and `strand` must be null or live handles, and `out` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_rewrite(
+    enzyme: *const TgEnzyme,
+    strand: *const TgStrand,
+    out: *mut *mut TgStrandList,
+) -> TgStatus {
+    let (Some(enzyme), Some(strand)) = (enzyme.as_ref(), strand.as_ref()) else {
+        return TgStatus::NullPointer;
+    };
+    TgStatus::guard(|| {
+        let products = Rewriter::rewrite(&enzyme.0, &strand.0);
+        write_handle(out, TgStrandList::new(products))
+    })
+}
+
+/// Release an enzyme. Does nothing for null.
+///
+/// # Safety
+///
+/// `enzyme` must be null or an enzyme handle that has not been freed. Enzymes borrowed
+/// from a list must not be freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_free(enzyme: *mut TgEnzyme) {
+    if !enzyme.is_null() {
+        drop(Box::from_raw(enzyme));
+    }
+}
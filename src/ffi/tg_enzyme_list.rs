@@ -0,0 +1,58 @@
+use crate::ffi::{TgEnzyme, TgStatus};
+use crate::typogenetics::Enzyme;
+
+/// Opaque handle to a list of enzymes returned by the library.
+pub struct TgEnzymeList(Vec<TgEnzyme>);
+
+impl TgEnzymeList {
+    pub(crate) fn new(enzymes: Vec<Enzyme>) -> TgEnzymeList {
+        TgEnzymeList(enzymes.into_iter().map(TgEnzyme).collect())
+    }
+}
+
+/// Number of enzymes in the list, or 0 for null.
+///
+/// # Safety
+///
+/// `list` must be null or a live list handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_list_len(list: *const TgEnzymeList) -> usize {
+    list.as_ref().map_or(0, |list| list.0.len())
+}
+
+/// Borrow the enzyme at an index. The enzyme lives as long as the list and must not be
+/// freed on its own.
+///
+/// # Safety
+///
+/// `list` must be null or a live list handle, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_list_get(
+    list: *const TgEnzymeList,
+    index: usize,
+    out: *mut *const TgEnzyme,
+) -> TgStatus {
+    let (Some(list), false) = (list.as_ref(), out.is_null()) else {
+        return TgStatus::NullPointer;
+    };
+    match list.0.get(index) {
+        Some(enzyme) => {
+            *out = enzyme;
+            TgStatus::Ok
+        }
+        None => TgStatus::IndexOutOfRange,
+    }
+}
+
+/// Release a list and the enzymes in it. Does nothing for null.
+///
+/// # Safety
+///
+/// `list` must be null or a list handle that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_enzyme_list_free(list: *mut TgEnzymeList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
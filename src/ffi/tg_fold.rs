@@ -0,0 +1,29 @@
+use std::ffi::c_char;
+
+use crate::typogenetics::{Enzyme, Folder, Orientation};
+
+/// How an enzyme folds: its final orientation, one of 'U', 'D', 'L' or 'R', and the
+/// base it binds to, one of 'A', 'C', 'G' or 'T'.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TgFold {
+    pub orientation: c_char,
+    pub binding_base: c_char,
+}
+
+impl TgFold {
+    pub(crate) fn from_enzyme(enzyme: &Enzyme) -> TgFold {
+        let orientation = Folder::fold(enzyme);
+        let orientation_char = match orientation {
+            Orientation::U => b'U',
+            Orientation::D => b'D',
+            Orientation::L => b'L',
+            Orientation::R => b'R',
+        };
+        let binding_base = Folder::get_binding_affinity(orientation).to_string();
+        TgFold {
+            orientation: orientation_char as c_char,
+            binding_base: binding_base.as_bytes()[0] as c_char,
+        }
+    }
+}
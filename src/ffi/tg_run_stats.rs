@@ -0,0 +1,8 @@
+/// Statistics of a finished simulation run.
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TgRunStats {
+    pub n_iterations: usize,
+    pub n_rewrites: usize,
+    pub elapsed_seconds: f64,
+}
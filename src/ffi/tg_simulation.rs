@@ -0,0 +1,151 @@
+use crate::ffi::tg_string::write_handle;
+use crate::ffi::{TgRunStats, TgStatus, TgStrand, TgStrandList};
+use crate::search::{ResourceLimits, Simulation};
+use crate::typogenetics::Strand;
+
+/// Opaque handle to the settings of a random simulation, see `Simulation`. A handle can
+/// be run any number of times.
+pub struct TgSimulation {
+    init_strand: Strand,
+    n_iterations: usize,
+    seed: u64,
+    limits: ResourceLimits,
+}
+
+/// Create a simulation starting from a strand, with the defaults of `Simulation`.
+///
+/// # Safety
+///
+/// `init_strand` must be null or a live strand handle, and `out` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_new(
+    init_strand: *const TgStrand,
+    out: *mut *mut TgSimulation,
+) -> TgStatus {
+    let Some(init_strand) = init_strand.as_ref() else {
+        return TgStatus::NullPointer;
+    };
+    let simulation = TgSimulation {
+        init_strand: init_strand.0.clone(),
+        n_iterations: 100_000,
+        seed: 2,
+        limits: ResourceLimits::unlimited(),
+    };
+    write_handle(out, simulation)
+}
+
+/// Set the number of iterations to run.
+///
+/// # Safety
+///
+/// `simulation` must be null or a live simulation handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_set_iterations(
+    simulation: *mut TgSimulation,
+    n_iterations: usize,
+) -> TgStatus {
+    let Some(simulation) = simulation.as_mut() else {
+        return TgStatus::NullPointer;
+    };
+    simulation.n_iterations = n_iterations;
+    TgStatus::Ok
+}
+
+/// Set the seed of the random number generator.
+///
+/// # Safety
+///
+/// `simulation` must be null or a live simulation handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_set_seed(
+    simulation: *mut TgSimulation,
+    seed: u64,
+) -> TgStatus {
+    let Some(simulation) = simulation.as_mut() else {
+        return TgStatus::NullPointer;
+    };
+    simulation.seed = seed;
+    TgStatus::Ok
+}
+
+/// Drop products longer than this many bases, or 0 for no limit.
+///
+/// # Safety
+///
+/// `simulation` must be null or a live simulation handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_set_max_strand_len(
+    simulation: *mut TgSimulation,
+    max_strand_len: usize,
+) -> TgStatus {
+    let Some(simulation) = simulation.as_mut() else {
+        return TgStatus::NullPointer;
+    };
+    simulation.limits.max_strand_len = (max_strand_len > 0).then_some(max_strand_len);
+    TgStatus::Ok
+}
+
+/// Drop new strands once the soup holds this many, or 0 for no limit.
+///
+/// # Safety
+///
+/// `simulation` must be null or a live simulation handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_set_max_population(
+    simulation: *mut TgSimulation,
+    max_population: usize,
+) -> TgStatus {
+    let Some(simulation) = simulation.as_mut() else {
+        return TgStatus::NullPointer;
+    };
+    simulation.limits.max_population = (max_population > 0).then_some(max_population);
+    TgStatus::Ok
+}
+
+/// Run the simulation, writing the discovered strands in the order they were found to
+/// a new list and the run statistics to `stats`, which may be null.
+///
+/// # Safety
+///
+/// `simulation` must be null or a live simulation handle, `out` must be null or valid
+/// for writes, and `stats` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_run(
+    simulation: *const TgSimulation,
+    out: *mut *mut TgStrandList,
+    stats: *mut TgRunStats,
+) -> TgStatus {
+    let (Some(simulation), false) = (simulation.as_ref(), out.is_null()) else {
+        return TgStatus::NullPointer;
+    };
+    TgStatus::guard(|| {
+        let result = Simulation::builder(std::slice::from_ref(&simulation.init_strand))
+            .iterations(simulation.n_iterations)
+            .seed(simulation.seed)
+            .limits(simulation.limits)
+            .build()
+            .expect("Simulation has an initial strand")
+            .run();
+        if let Some(stats) = stats.as_mut() {
+            *stats = TgRunStats {
+                n_iterations: result.n_iterations,
+                n_rewrites: result.n_rewrites,
+                elapsed_seconds: result.elapsed.as_secs_f64(),
+            };
+        }
+        write_handle(out, TgStrandList::new(result.strands))
+    })
+}
+
+/// Release a simulation. Does nothing for null.
+///
+/// # Safety
+///
+/// `simulation` must be null or a simulation handle that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_simulation_free(simulation: *mut TgSimulation) {
+    if !simulation.is_null() {
+        drop(Box::from_raw(simulation));
+    }
+}
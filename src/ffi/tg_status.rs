@@ -0,0 +1,37 @@
+use std::ffi::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Result of a call into the C API.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TgStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    InvalidStrand = 3,
+    InvalidEnzyme = 4,
+    IndexOutOfRange = 5,
+    InternalError = 6,
+}
+
+impl TgStatus {
+    /// Run a call, turning a panic into `InternalError` so it never unwinds into C.
+    pub(crate) fn guard(call: impl FnOnce() -> TgStatus) -> TgStatus {
+        catch_unwind(AssertUnwindSafe(call)).unwrap_or(TgStatus::InternalError)
+    }
+}
+
+/// A static description of a status, which must not be freed.
+#[no_mangle]
+pub extern "C" fn tg_status_message(status: TgStatus) -> *const c_char {
+    let message = match status {
+        TgStatus::Ok => c"ok",
+        TgStatus::NullPointer => c"null pointer argument",
+        TgStatus::InvalidUtf8 => c"string is not valid UTF-8",
+        TgStatus::InvalidStrand => c"invalid strand, expected bases A, C, G and T",
+        TgStatus::InvalidEnzyme => c"invalid enzyme, expected amino acids separated by '-'",
+        TgStatus::IndexOutOfRange => c"index out of range",
+        TgStatus::InternalError => c"internal error",
+    };
+    message.as_ptr()
+}
@@ -0,0 +1,79 @@
+use std::ffi::c_char;
+
+use crate::ffi::tg_string::{read_str, to_c_string, write_handle};
+use crate::ffi::{TgEnzymeList, TgStatus};
+use crate::typogenetics::{Strand, Translator};
+
+/// Opaque handle to a strand.
+pub struct TgStrand(pub(crate) Strand);
+
+/// Parse a strand of bases A, C, G and T.
+///
+/// # Safety
+///
+/// `bases` must be null or a NUL terminated string, and `out` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_new(bases: *const c_char, out: *mut *mut TgStrand) -> TgStatus {
+    let bases = match read_str(bases) {
+        Ok(bases) => bases,
+        Err(status) => return status,
+    };
+    match Strand::try_from_string(bases) {
+        Ok(strand) => write_handle(out, TgStrand(strand)),
+        Err(_) => TgStatus::InvalidStrand,
+    }
+}
+
+/// Number of bases in the strand, or 0 for null.
+///
+/// # Safety
+///
+/// `strand` must be null or a live strand handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_len(strand: *const TgStrand) -> usize {
+    strand.as_ref().map_or(0, |strand| strand.0.len())
+}
+
+/// The bases of the strand, to be released with `tg_string_free`, or null for null.
+///
+/// # Safety
+///
+/// `strand` must be null or a live strand handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_to_string(strand: *const TgStrand) -> *mut c_char {
+    match strand.as_ref() {
+        Some(strand) => to_c_string(strand.0.to_string()),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Release a strand. Does nothing for null.
+///
+/// # Safety
+///
+/// `strand` must be null or a strand handle that has not been freed. Strands borrowed
+/// from a list must not be freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_free(strand: *mut TgStrand) {
+    if !strand.is_null() {
+        drop(Box::from_raw(strand));
+    }
+}
+
+/// Translate the strand, writing its enzymes to a new list.
+///
+/// # Safety
+///
+/// `strand` must be null or a live strand handle, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_translate(
+    strand: *const TgStrand,
+    out: *mut *mut TgEnzymeList,
+) -> TgStatus {
+    let Some(strand) = strand.as_ref() else {
+        return TgStatus::NullPointer;
+    };
+    TgStatus::guard(|| write_handle(out, TgEnzymeList::new(Translator::translate(&strand.0))))
+}
@@ -0,0 +1,58 @@
+use crate::ffi::{TgStatus, TgStrand};
+use crate::typogenetics::Strand;
+
+/// Opaque handle to a list of strands returned by the library.
+pub struct TgStrandList(Vec<TgStrand>);
+
+impl TgStrandList {
+    pub(crate) fn new(strands: Vec<Strand>) -> TgStrandList {
+        TgStrandList(strands.into_iter().map(TgStrand).collect())
+    }
+}
+
+/// Number of strands in the list, or 0 for null.
+///
+/// # Safety
+///
+/// `list` must be null or a live list handle.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_list_len(list: *const TgStrandList) -> usize {
+    list.as_ref().map_or(0, |list| list.0.len())
+}
+
+/// Borrow the strand at an index. The strand lives as long as the list and must not be
+/// freed on its own.
+///
+/// # Safety
+///
+/// `list` must be null or a live list handle, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_list_get(
+    list: *const TgStrandList,
+    index: usize,
+    out: *mut *const TgStrand,
+) -> TgStatus {
+    let (Some(list), false) = (list.as_ref(), out.is_null()) else {
+        return TgStatus::NullPointer;
+    };
+    match list.0.get(index) {
+        Some(strand) => {
+            *out = strand;
+            TgStatus::Ok
+        }
+        None => TgStatus::IndexOutOfRange,
+    }
+}
+
+/// Release a list and the strands in it. Does nothing for null.
+///
+/// # Safety
+///
+/// `list` must be null or a list handle that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_strand_list_free(list: *mut TgStrandList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
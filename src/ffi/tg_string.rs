@@ -0,0 +1,48 @@
+use std::ffi::{c_char, CStr, CString};
+
+use crate::ffi::TgStatus;
+
+/// Borrow a C string as UTF-8.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL terminated string that outlives the result.
+pub(crate) unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, TgStatus> {
+    if s.is_null() {
+        return Err(TgStatus::NullPointer);
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| TgStatus::InvalidUtf8)
+}
+
+/// Hand a string to C, to be released with `tg_string_free`.
+pub(crate) fn to_c_string(s: String) -> *mut c_char {
+    // Strands and enzymes never contain NUL
+    CString::new(s).expect("String contains NUL").into_raw()
+}
+
+/// Move a value into a new handle and write it through `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+pub(crate) unsafe fn write_handle<T>(out: *mut *mut T, value: T) -> TgStatus {
+    if out.is_null() {
+        return TgStatus::NullPointer;
+    }
+    *out = Box::into_raw(Box::new(value));
+    TgStatus::Ok
+}
+
+/// Release a string returned by the library. Does nothing for null.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by the library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn tg_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
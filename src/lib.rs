@@ -1,2 +1,14 @@
+pub mod analysis;
+pub mod batch;
+pub mod conformance;
+pub mod fasta;
+pub mod ffi;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod query;
+pub mod repl;
 pub mod search;
+pub mod svg;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod typogenetics;
@@ -1,6 +1,27 @@
 use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
-use typogenetics::search::SearchAlgos;
-use typogenetics::typogenetics::{Enzyme, Rewriter, Strand, Translator};
+use typogenetics::analysis::{
+    EquivalenceChecker, PathwayFinder, RafFinder, Reaction, SelfApplier, SoupStats,
+    SymbolicExecutor,
+};
+use typogenetics::batch::{BatchRunner, BatchWriter, OutputFormat};
+use typogenetics::conformance::{ConformanceReader, ConformanceRunner};
+use typogenetics::fasta::FastaReader;
+use typogenetics::query::Filter;
+use typogenetics::repl::{Interpreter, Session};
+use typogenetics::search::{
+    Checkpoint, CheckpointSchedule, CheckpointState, FitnessMetric, GenerationLimits, LimitPolicy,
+    ParallelConfig, ResourceLimits, RewriteCache, SearchAlgos, StopCondition, StrandFormat,
+    StrandOutput,
+};
+use typogenetics::svg::SvgRenderer;
+#[cfg(feature = "tui")]
+use typogenetics::tui::StepperApp;
+use typogenetics::typogenetics::{Enzyme, Folder, Motif, Rewriter, Strand, Translator};
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, IsTerminal};
+use std::path::Path;
+use std::time::Duration;
 
 use clap::{Parser, Subcommand};
 
@@ -12,39 +33,359 @@ struct Cli {
     command: Option<Commands>,
 }
 
+// Parsed once per run, so the size of the simulate variant does not matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
     /// Translate a strand into a list of enzymes
     Translate {
-        /// Strand to translate
-        strand_str: String,
+        /// Strand to translate, or @FILE[:ID] to read it from FASTA
+        #[arg(required_unless_present = "input")]
+        strand_str: Option<String>,
+
+        /// File of strands to translate, FASTA or one "[ID] STRAND" per line, or - for stdin
+        #[arg(long, conflicts_with = "strand_str")]
+        input: Option<String>,
+
+        /// Format of batch results, tsv or jsonl
+        #[arg(long, default_value = "tsv", requires = "input")]
+        format: OutputFormat,
+
+        /// Translate only the input strands matching a filter, such as 'len > 10'
+        #[arg(long = "where", value_name = "FILTER", requires = "input")]
+        filter: Option<Filter>,
     },
 
     /// Rewrite a strand using an enzyme
     Rewrite {
+        /// Enzyme to apply to strand, or to every input strand in batch mode
+        #[arg(required_unless_present = "input")]
+        enzyme_str: Option<String>,
+
+        /// Strand to rewrite with enzyme, or @FILE[:ID] to read it from FASTA
+        #[arg(required_unless_present = "input")]
+        strand_str: Option<String>,
+
+        /// File of rewrites, one "[ID] ENZYME STRAND" per line, or - for stdin. With an
+        /// enzyme argument, FASTA or one "[ID] STRAND" per line
+        #[arg(long, conflicts_with = "strand_str")]
+        input: Option<String>,
+
+        /// Output format, tsv, jsonl or fasta. Batch results default to tsv and single
+        /// rewrites print one product per line unless fasta is chosen
+        #[arg(long)]
+        format: Option<OutputFormat>,
+
+        /// Keep only the batch products matching a filter, such as 'binds(G)'
+        #[arg(long = "where", value_name = "FILTER", requires = "input")]
+        filter: Option<Filter>,
+    },
+
+    /// Rewrite a strand with each of its own enzymes
+    ApplySelf {
+        /// Strand to apply to itself, or @FILE[:ID] to read it from FASTA
+        strand_str: String,
+
+        /// Number of generations, each applying every new product to itself
+        #[arg(long, default_value_t = 1)]
+        generations: usize,
+    },
+
+    /// Fold an enzyme onto a lattice and draw it
+    Fold {
+        /// Enzyme to fold
+        enzyme_str: String,
+    },
+
+    /// Step through a rewrite interactively in the terminal
+    #[cfg(feature = "tui")]
+    Step {
         /// Enzyme to apply to strand
         enzyme_str: String,
 
-        /// Strand to rewrite with enzyme
+        /// Strand to rewrite with enzyme, or @FILE[:ID] to read it from FASTA
         strand_str: String,
     },
 
+    /// Symbolically execute an enzyme over a strand of unknown bases
+    Symbolic {
+        /// Enzyme to execute
+        enzyme_str: String,
+
+        /// Maximum number of bases to explore on either side of the binding site
+        #[arg(long, default_value_t = 6)]
+        max_flank: usize,
+    },
+
+    /// Check whether two enzymes rewrite every strand up to a length identically
+    Equiv {
+        /// First enzyme to compare
+        enzyme_a_str: String,
+
+        /// Second enzyme to compare
+        enzyme_b_str: String,
+
+        /// Maximum length of strands to check
+        #[arg(long, default_value_t = 8)]
+        max_len: usize,
+
+        /// Number of worker threads, defaults to the available parallelism
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+
+    /// Explore strands, enzymes and a working soup interactively
+    Repl {
+        /// Session file to load on startup
+        session_path: Option<String>,
+    },
+
+    /// Find autocatalytic sets among the reactions of a generational simulation
+    Autocatalytic {
+        /// Food strands to start from, or @FILE[:ID] to read them from FASTA. A file
+        /// without an ID adds every record in it
+        #[arg(required = true)]
+        food_strs: Vec<String>,
+
+        /// Number of generations to simulate
+        #[arg(long, default_value_t = 4)]
+        generations: usize,
+
+        /// Maximum number of strands in the set during the simulation
+        #[arg(long, default_value_t = 2_000)]
+        max_strands: usize,
+
+        /// Maximum length of strands kept during the simulation
+        #[arg(long, default_value_t = 60)]
+        max_len: usize,
+
+        /// Maximum number of irreducible subsets to report
+        #[arg(long, default_value_t = 10)]
+        max_subsets: usize,
+
+        /// Number of translations and of rewrites to cache, 0 to bypass caching. Few rewrites
+        /// repeat in a typical soup, where caching costs more than it saves, so it is off by
+        /// default
+        #[arg(long, default_value_t = 0)]
+        cache_capacity: usize,
+
+        /// Print cache hit and miss counts to stderr at the end
+        #[arg(long, default_value_t = false)]
+        cache_stats: bool,
+    },
+
+    /// Check golden cases of translations and rewrites and report any differences
+    Verify {
+        /// Corpus files, or directories of .cases files
+        #[arg(default_value = "corpus")]
+        paths: Vec<String>,
+    },
+
+    /// Find the fewest reactions by which a target strand arises from starting strands
+    Pathway {
+        /// Strands to start from, or @FILE[:ID] to read them from FASTA. A file without
+        /// an ID adds every record in it
+        #[arg(required = true)]
+        start_strs: Vec<String>,
+
+        /// Strand to produce, or @FILE[:ID] to read it from FASTA
+        #[arg(long)]
+        target: String,
+
+        /// Maximum number of generations of reactions to search
+        #[arg(long, default_value_t = 4)]
+        generations: usize,
+
+        /// Maximum number of strands known during the search
+        #[arg(long, default_value_t = 2_000)]
+        max_strands: usize,
+
+        /// Maximum length of strands used during the search
+        #[arg(long, default_value_t = 60)]
+        max_len: usize,
+
+        /// Print the pathway as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+
+        /// Number of translations and of rewrites to cache, 0 to bypass caching
+        #[arg(long, default_value_t = 0)]
+        cache_capacity: usize,
+
+        /// Print cache hit and miss counts to stderr at the end
+        #[arg(long, default_value_t = false)]
+        cache_stats: bool,
+    },
+
+    /// Report statistics of a set of strands and the enzymes they code for
+    Stats {
+        /// File of strands, FASTA such as the output of simulate --print-strands --fasta or
+        /// one "[ID] STRAND" per line, or - for stdin
+        input: String,
+
+        /// Number of most common enzymes to list
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+
+        /// Only count the strands matching a filter, such as 'class(self-replicator)'
+        #[arg(long = "where", value_name = "FILTER")]
+        filter: Option<Filter>,
+
+        /// Print the statistics as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Render an enzyme, strand or rewrite trace as SVG
+    Render {
+        #[command(subcommand)]
+        target: RenderTarget,
+
+        /// File to write the SVG to, defaults to stdout
+        #[arg(long, short, global = true)]
+        output: Option<String>,
+    },
+
     /// Simulate generations of enzyme application
     Simulate {
-        /// Initial strand to start the simulation
-        init_strand_str: String,
+        /// Initial strand to start the simulation, or @FILE[:ID] to read it from FASTA
+        #[arg(required_unless_present = "resume")]
+        init_strand_str: Option<String>,
 
         /// Number of iterations to simulate
         #[arg(long = "iter", default_value_t = 100_000)]
         n_iterations: i32,
 
+        /// Simulate deterministically for up to this many generations, applying every
+        /// enzyme to every strand in each, instead of sampling random iterations
+        #[arg(long, conflicts_with_all = ["n_iterations", "seed"])]
+        generations: Option<usize>,
+
         /// Random seed
         #[arg(long)]
         seed: Option<i32>,
 
+        /// Simulate on this many worker threads sharing one soup
+        #[arg(long, conflicts_with = "generations")]
+        threads: Option<usize>,
+
+        /// Make a threaded simulation give the same result for any number of threads
+        #[arg(long, default_value_t = false, requires = "threads")]
+        deterministic: bool,
+
+        /// Repeat the simulation with this many consecutive seeds and report statistics
+        /// across the runs. Not for generational simulations, which take no seed and would
+        /// repeat the same run
+        #[arg(long, conflicts_with_all = ["generations", "cache_stats"])]
+        replicates: Option<usize>,
+
         /// Whether to print all discovered strands at the end of simulation
         #[arg(long, default_value_t = false)]
         print_strands: bool,
+
+        /// Print discovered strands as FASTA
+        #[arg(long, default_value_t = false, requires = "print_strands")]
+        fasta: bool,
+
+        /// Print only the strands matching a filter, such as
+        /// 'len > 10 and contains("TAG") and binds(G)'
+        #[arg(long = "where", value_name = "FILTER", requires = "print_strands")]
+        filter: Option<Filter>,
+
+        /// Number of translations and of rewrites to cache, 0 to bypass caching. Few rewrites
+        /// repeat in a typical soup, where caching costs more than it saves, so it is off by
+        /// default
+        #[arg(long, default_value_t = 0)]
+        cache_capacity: usize,
+
+        /// Print cache hit and miss counts to stderr at the end
+        #[arg(long, default_value_t = false)]
+        cache_stats: bool,
+
+        /// Maximum length of strands kept in the soup, 100 by default in a generational
+        /// simulation
+        #[arg(long)]
+        max_strand_len: Option<usize>,
+
+        /// Maximum number of strands in the soup, 10000 by default in a generational
+        /// simulation
+        #[arg(long)]
+        max_population: Option<usize>,
+
+        /// Maximum number of bases across all strands in the soup
+        #[arg(long)]
+        max_total_bases: Option<usize>,
+
+        /// Stop the run after this many seconds
+        #[arg(long)]
+        time_limit: Option<f64>,
+
+        /// Stop the run after this many rewrites
+        #[arg(long)]
+        max_rewrites: Option<usize>,
+
+        /// What to do with a product that breaks a limit on the soup, drop, stop or evict
+        #[arg(long, default_value = "drop")]
+        limit_policy: LimitPolicy,
+
+        /// File to periodically write a checkpoint to, so the run can be resumed
+        #[arg(long, conflicts_with_all = ["threads", "replicates"])]
+        checkpoint: Option<String>,
+
+        /// Iterations, or generations, between checkpoints
+        #[arg(long, default_value_t = 100_000, requires = "checkpoint")]
+        checkpoint_every: usize,
+
+        /// Continue the run saved in a checkpoint, up to the total given by --iter or
+        /// --generations
+        #[arg(long, conflicts_with_all = ["init_strand_str", "threads", "replicates"])]
+        resume: Option<String>,
+
+        /// Stop when this strand is discovered
+        #[arg(long)]
+        stop_at: Option<String>,
+
+        /// Stop when a strand containing this motif is discovered, where R matches a
+        /// purine, Y a pyrimidine and N any base
+        #[arg(long)]
+        stop_pattern: Option<String>,
+
+        /// Stop when a strand that copies itself when applied to itself is discovered
+        #[arg(long, default_value_t = false)]
+        stop_self_replicator: bool,
+
+        /// Stop when no new strand has been discovered for this many iterations
+        #[arg(long, conflicts_with = "generations")]
+        stop_stagnant: Option<usize>,
+
+        /// Stop when a strand reaches a fitness threshold, given as METRIC:VALUE with
+        /// METRIC one of length, enzymes or self-products
+        #[arg(long, value_parser = parse_fitness)]
+        stop_fitness: Option<(FitnessMetric, usize)>,
+    },
+}
+
+#[derive(Subcommand)]
+enum RenderTarget {
+    /// Draw the folded tertiary structure of an enzyme
+    Enzyme {
+        /// Enzyme to draw
+        enzyme_str: String,
+    },
+
+    /// Draw a strand paired with its complement
+    Strand {
+        /// Strand to draw, or @FILE[:ID] to read it from FASTA
+        strand_str: String,
+    },
+
+    /// Draw every step of an enzyme rewriting a strand
+    Trace {
+        /// Enzyme to apply to strand
+        enzyme_str: String,
+
+        /// Strand to rewrite with enzyme, or @FILE[:ID] to read it from FASTA
+        strand_str: String,
     },
 }
 
@@ -54,36 +395,617 @@ fn main() {
     let cli = Cli::parse();
 
     match &cli.command {
-        Some(Commands::Translate { strand_str }) => {
-            let strand = Strand::from_string(strand_str);
-            let enzymes = Translator::translate(&strand);
-            for enzyme in enzymes {
-                println!("{}", enzyme);
+        Some(Commands::Translate {
+            strand_str,
+            input,
+            format,
+            filter,
+        }) => {
+            if let Some(input) = input {
+                let mut writer = BatchWriter::new(std::io::stdout().lock(), *format);
+                let n_failed =
+                    BatchRunner::translate(open_input(input), filter.as_ref(), &mut writer)
+                        .unwrap_or_else(|e| exit_with_error(&e.to_string()));
+                exit_on_failures(n_failed);
+            } else if let Some(strand_str) = strand_str {
+                let strand = parse_strand(strand_str);
+                let enzymes = Translator::translate(&strand);
+                for enzyme in enzymes {
+                    println!("{}", enzyme);
+                }
             }
         }
         Some(Commands::Rewrite {
             enzyme_str,
             strand_str,
+            input,
+            format,
+            filter,
+        }) => {
+            let enzyme = enzyme_str.as_deref().map(Enzyme::from_string);
+            if let Some(input) = input {
+                let format = format.unwrap_or(OutputFormat::Tsv);
+                let mut writer = BatchWriter::new(std::io::stdout().lock(), format);
+                let n_failed = BatchRunner::rewrite(
+                    open_input(input),
+                    enzyme.as_ref(),
+                    filter.as_ref(),
+                    &mut writer,
+                )
+                .unwrap_or_else(|e| exit_with_error(&e.to_string()));
+                exit_on_failures(n_failed);
+            } else if let (Some(enzyme), Some(strand_str)) = (enzyme, strand_str) {
+                let strand = parse_strand(strand_str);
+                let new_strands = Rewriter::rewrite(&enzyme, &strand);
+                if *format == Some(OutputFormat::Fasta) {
+                    let mut writer =
+                        BatchWriter::new(std::io::stdout().lock(), OutputFormat::Fasta);
+                    writer
+                        .write_strands("product", &new_strands)
+                        .expect("Failed to write products");
+                } else {
+                    for new_strand in new_strands {
+                        println!("{}", new_strand);
+                    }
+                }
+            }
+        }
+        Some(Commands::ApplySelf {
+            strand_str,
+            generations,
+        }) => {
+            let strand = parse_strand(strand_str);
+            let results = SelfApplier::iterate(&strand, *generations);
+            for (i, applications) in results.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("Generation {}", i + 1);
+                if applications.is_empty() {
+                    println!("No enzymes to apply");
+                    continue;
+                }
+                let mut rows = vec![[
+                    String::from("strand"),
+                    String::from("enzyme"),
+                    String::from("binds"),
+                    String::from("site"),
+                    String::from("products"),
+                ]];
+                for application in applications {
+                    let products: Vec<String> =
+                        application.products.iter().map(|s| s.to_string()).collect();
+                    rows.push([
+                        application.strand.to_string(),
+                        application.enzyme.to_string(),
+                        application.binding_base.to_string(),
+                        application
+                            .binding_site
+                            .map_or(String::from("-"), |site| site.to_string()),
+                        products.join(" "),
+                    ]);
+                }
+                print_table(&rows);
+            }
+            if results.len() < *generations {
+                println!();
+                println!("No new strands after generation {}", results.len());
+            }
+        }
+        Some(Commands::Fold { enzyme_str }) => {
+            let enzyme = Enzyme::from_string(enzyme_str);
+            let fold_path = Folder::fold_path(&enzyme);
+            let ((min_x, min_y), (max_x, max_y)) = fold_path.get_bounding_box();
+            let (end_x, end_y) = fold_path.get_end_to_end();
+            println!("{}", fold_path.to_ascii());
+            println!();
+            println!(
+                "Orientation: {:?}, binds {}",
+                fold_path.orientation,
+                Folder::get_binding_affinity(fold_path.orientation)
+            );
+            println!(
+                "Bounding box: ({}, {}) to ({}, {})",
+                min_x, min_y, max_x, max_y
+            );
+            println!("End to end: ({}, {})", end_x, end_y);
+            let intersections = fold_path.get_intersections();
+            if intersections.is_empty() {
+                println!("Self-intersecting: no");
+            } else {
+                let points: Vec<String> = intersections
+                    .iter()
+                    .map(|(x, y)| format!("({}, {})", x, y))
+                    .collect();
+                println!("Self-intersecting: yes, at {}", points.join(" "));
+            }
+        }
+        #[cfg(feature = "tui")]
+        Some(Commands::Step {
+            enzyme_str,
+            strand_str,
+        }) => {
+            let enzyme = Enzyme::from_string(enzyme_str);
+            let strand = parse_strand(strand_str);
+            StepperApp::run(&enzyme, &strand).expect("Failed to run terminal UI");
+        }
+        Some(Commands::Symbolic {
+            enzyme_str,
+            max_flank,
         }) => {
             let enzyme = Enzyme::from_string(enzyme_str);
-            let strand = Strand::from_string(strand_str);
-            let new_strands = Rewriter::rewrite(&enzyme, &strand);
-            for new_strand in new_strands {
-                println!("{}", new_strand);
+            let binding_base = SymbolicExecutor::get_binding_base(&enzyme);
+            let paths = SymbolicExecutor::execute(&enzyme, *max_flank);
+            println!("{} binds to x0 = {}", enzyme, binding_base);
+            for (i, path) in paths.iter().enumerate() {
+                println!();
+                println!("Path {}", i + 1);
+                println!("{}", path);
+            }
+        }
+        Some(Commands::Equiv {
+            enzyme_a_str,
+            enzyme_b_str,
+            max_len,
+            threads,
+        }) => {
+            let enzyme_a = Enzyme::from_string(enzyme_a_str);
+            let enzyme_b = Enzyme::from_string(enzyme_b_str);
+            let n_threads = threads
+                .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+            let result = EquivalenceChecker::check(&enzyme_a, &enzyme_b, *max_len, n_threads)
+                .unwrap_or_else(|e| exit_with_error(&e));
+            match result {
+                None => println!(
+                    "{} and {} are equivalent on all strands up to length {}",
+                    enzyme_a, enzyme_b, max_len
+                ),
+                Some(counterexample) => {
+                    println!(
+                        "{} and {} differ on {}",
+                        enzyme_a, enzyme_b, counterexample.strand
+                    );
+                    let join = |strands: &[Strand]| {
+                        strands
+                            .iter()
+                            .map(|s| s.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    };
+                    println!("- {}: {}", enzyme_a, join(&counterexample.products_a));
+                    println!("- {}: {}", enzyme_b, join(&counterexample.products_b));
+                }
+            }
+        }
+        Some(Commands::Repl { session_path }) => {
+            let mut session = Session::new();
+            if let Some(session_path) = session_path {
+                if let Err(message) = session.load(session_path) {
+                    panic!("{}", message);
+                }
+            }
+            let prompt = std::io::stdin().is_terminal();
+            let mut input = std::io::stdin().lock();
+            let mut output = std::io::stdout().lock();
+            Interpreter::run(&mut session, &mut input, &mut output, prompt)
+                .expect("Failed to run REPL");
+        }
+        Some(Commands::Autocatalytic {
+            food_strs,
+            generations,
+            max_strands,
+            max_len,
+            max_subsets,
+            cache_capacity,
+            cache_stats,
+        }) => {
+            let mut cache = RewriteCache::new(*cache_capacity);
+            let food: Vec<Strand> = food_strs.iter().flat_map(|f| parse_strands(f)).collect();
+            let limits = GenerationLimits::new(*generations, *max_strands, *max_len);
+            let mut reactions = Vec::new();
+            let strands = SearchAlgos::run_generations(
+                &food,
+                limits,
+                &mut cache,
+                |summary| log::info!("{}", summary),
+                |catalyst, enzyme, reactant, products| {
+                    // An enzyme that cannot bind leaves the strand as it is
+                    if products != std::slice::from_ref(reactant) {
+                        reactions.push(Reaction {
+                            catalyst: catalyst.clone(),
+                            enzyme: enzyme.clone(),
+                            reactant: reactant.clone(),
+                            products: products.to_vec(),
+                        });
+                    }
+                },
+            );
+            if *cache_stats {
+                eprintln!("{}", cache.get_stats());
+            }
+            println!(
+                "Recorded {} reactions among {} strands",
+                reactions.len(),
+                strands.len()
+            );
+
+            let Some(max_raf) = RafFinder::find_max(&food, &reactions) else {
+                println!("No autocatalytic set found");
+                return;
+            };
+            println!();
+            println!(
+                "Maximal RAF: {} reactions, {} strands",
+                max_raf.reactions.len(),
+                max_raf.strands.len()
+            );
+            for reaction in &max_raf.reactions {
+                println!("- {}", reaction);
+            }
+
+            let irreducible = RafFinder::find_irreducible(&food, &max_raf, *max_subsets);
+            for (i, raf) in irreducible.iter().enumerate() {
+                println!();
+                println!(
+                    "Irreducible RAF {}: {} reactions, {} strands",
+                    i + 1,
+                    raf.reactions.len(),
+                    raf.strands.len()
+                );
+                for reaction in &raf.reactions {
+                    println!("- {}", reaction);
+                }
+            }
+        }
+        Some(Commands::Verify { paths }) => {
+            let mut cases = Vec::new();
+            for path in paths {
+                let path_cases = ConformanceReader::read_path(Path::new(path))
+                    .unwrap_or_else(|e| exit_with_error(&e));
+                cases.extend(path_cases);
+            }
+            let report = ConformanceRunner::verify(&cases);
+            println!("{}", report);
+            if !report.is_success() {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Pathway {
+            start_strs,
+            target,
+            generations,
+            max_strands,
+            max_len,
+            json,
+            cache_capacity,
+            cache_stats,
+        }) => {
+            let mut cache = RewriteCache::new(*cache_capacity);
+            let start: Vec<Strand> = start_strs.iter().flat_map(|s| parse_strands(s)).collect();
+            let target = parse_strand(target);
+            let limits = GenerationLimits::new(*generations, *max_strands, *max_len);
+            let pathway = PathwayFinder::find(&start, &target, limits, &mut cache);
+            if *cache_stats {
+                eprintln!("{}", cache.get_stats());
+            }
+            match pathway {
+                Some(pathway) if *json => println!("{}", pathway.to_json()),
+                Some(pathway) => println!("{}", pathway),
+                None => exit_with_error(&format!(
+                    "No pathway to {} within {} generations",
+                    target, generations
+                )),
+            }
+        }
+        Some(Commands::Stats {
+            input,
+            top,
+            filter,
+            json,
+        }) => {
+            let records = BatchRunner::read_records(open_input(input), 1)
+                .unwrap_or_else(|e| exit_with_error(&e.to_string()));
+            let mut strands = Vec::new();
+            let mut n_failed = 0;
+            for record in records {
+                let strand = record.and_then(|record| {
+                    Strand::try_from_string(&record.fields[0])
+                        .map_err(|e| format!("line {}: {}", record.line_number, e))
+                });
+                match strand {
+                    Ok(strand) => strands.push(strand),
+                    Err(message) => {
+                        log::error!("{}", message);
+                        n_failed += 1;
+                    }
+                }
+            }
+            if let Some(filter) = filter {
+                strands.retain(|strand| filter.matches(strand));
+            }
+
+            let stats = SoupStats::from_strands(&strands, *top);
+            if *json {
+                println!("{}", stats.to_json());
+            } else {
+                println!("{}", stats);
+            }
+            exit_on_failures(n_failed);
+        }
+        Some(Commands::Render { target, output }) => {
+            let document = match target {
+                RenderTarget::Enzyme { enzyme_str } => {
+                    SvgRenderer::render_enzyme(&Enzyme::from_string(enzyme_str))
+                }
+                RenderTarget::Strand { strand_str } => {
+                    SvgRenderer::render_strand(&parse_strand(strand_str))
+                }
+                RenderTarget::Trace {
+                    enzyme_str,
+                    strand_str,
+                } => SvgRenderer::render_trace(
+                    &Enzyme::from_string(enzyme_str),
+                    &parse_strand(strand_str),
+                ),
+            };
+            match output {
+                Some(path) => std::fs::write(path, document.to_string())
+                    .unwrap_or_else(|e| panic!("Failed to write {}: {}", path, e)),
+                None => print!("{}", document),
             }
         }
         Some(Commands::Simulate {
             init_strand_str,
             n_iterations,
-            seed: _,
+            generations,
+            seed,
+            threads,
+            deterministic,
+            replicates,
             print_strands,
+            fasta,
+            filter,
+            cache_capacity,
+            cache_stats,
+            max_strand_len,
+            max_population,
+            max_total_bases,
+            time_limit,
+            max_rewrites,
+            limit_policy,
+            checkpoint,
+            checkpoint_every,
+            resume,
+            stop_at,
+            stop_pattern,
+            stop_self_replicator,
+            stop_stagnant,
+            stop_fitness,
         }) => {
-            let mut rng = ChaCha8Rng::seed_from_u64(2);
-            let init_strand = Strand::from_string(init_strand_str);
-            SearchAlgos::random(&init_strand, *n_iterations, &mut rng, *print_strands);
+            let mut cache = RewriteCache::new(*cache_capacity);
+            let seed = seed.map_or(2, |seed| seed as u64);
+            let init_strand = init_strand_str.as_deref().map(parse_strand);
+            let strand_output = print_strands.then(|| StrandOutput {
+                format: if *fasta {
+                    StrandFormat::Fasta
+                } else {
+                    StrandFormat::Plain
+                },
+                filter: filter.clone(),
+            });
+            let mut stop_conditions = Vec::new();
+            if let Some(stop_at) = stop_at {
+                stop_conditions.push(StopCondition::Target(parse_strand(stop_at)));
+            }
+            if let Some(pattern) = stop_pattern {
+                let motif = Motif::try_from_string(pattern).unwrap_or_else(|e| exit_with_error(&e));
+                stop_conditions.push(StopCondition::Pattern(motif));
+            }
+            if *stop_self_replicator {
+                stop_conditions.push(StopCondition::SelfReplicator);
+            }
+            if let Some(n) = stop_stagnant {
+                stop_conditions.push(StopCondition::Stagnation(*n));
+            }
+            if let Some((metric, threshold)) = stop_fitness {
+                stop_conditions.push(StopCondition::Fitness {
+                    metric: *metric,
+                    threshold: *threshold,
+                });
+            }
+            let limits = ResourceLimits {
+                max_strand_len: *max_strand_len,
+                max_population: *max_population,
+                max_total_bases: *max_total_bases,
+                time_limit: time_limit.map(Duration::from_secs_f64),
+                max_rewrites: *max_rewrites,
+                policy: *limit_policy,
+            };
+            let parallel = threads.map(|n_threads| ParallelConfig {
+                n_threads,
+                seed,
+                deterministic: *deterministic,
+                cache_capacity: *cache_capacity,
+            });
+            if let Some(n_replicates) = replicates {
+                SearchAlgos::replicates(
+                    init_strand
+                        .as_ref()
+                        .expect("Replicates need an initial strand"),
+                    (*n_iterations).max(0) as usize,
+                    *n_replicates,
+                    seed,
+                    *cache_capacity,
+                    parallel,
+                    &limits,
+                    &stop_conditions,
+                    strand_output.as_ref(),
+                );
+                return;
+            }
+            if let Some(config) = parallel {
+                let n_iterations = (*n_iterations).max(0) as usize;
+                let stats = SearchAlgos::parallel(
+                    init_strand
+                        .as_ref()
+                        .expect("Threads need an initial strand"),
+                    n_iterations,
+                    config,
+                    &limits,
+                    &stop_conditions,
+                    strand_output.as_ref(),
+                );
+                if *cache_stats {
+                    eprintln!("{}", stats);
+                }
+                return;
+            }
+
+            let start = match (resume, init_strand) {
+                (Some(path), _) => Checkpoint::load(path).unwrap_or_else(|e| exit_with_error(&e)),
+                (None, Some(init_strand)) => {
+                    let state = match generations {
+                        Some(_) => CheckpointState::Generational { n_done: 0 },
+                        None => CheckpointState::Random {
+                            rng: Box::new(ChaCha8Rng::seed_from_u64(seed)),
+                            last_new_iteration: 0,
+                        },
+                    };
+                    Checkpoint::start(&[init_strand], state)
+                }
+                (None, None) => exit_with_error("No initial strand or checkpoint"),
+            };
+            let schedule = checkpoint.as_ref().map(|path| CheckpointSchedule {
+                path: path.clone(),
+                every: *checkpoint_every,
+            });
+            let result = if let Some(generations) = generations {
+                // A generational search grows without bound unless the soup is capped
+                let limits = GenerationLimits {
+                    max_generations: *generations,
+                    resources: ResourceLimits {
+                        max_strand_len: max_strand_len.or(Some(100)),
+                        max_population: max_population.or(Some(10_000)),
+                        ..limits
+                    },
+                };
+                SearchAlgos::generational_from(
+                    start,
+                    limits,
+                    &mut cache,
+                    schedule.as_ref(),
+                    &stop_conditions,
+                    strand_output.as_ref(),
+                )
+            } else {
+                SearchAlgos::random_from(
+                    start,
+                    (*n_iterations).max(0) as usize,
+                    &mut cache,
+                    schedule.as_ref(),
+                    &limits,
+                    &stop_conditions,
+                    strand_output.as_ref(),
+                )
+            };
+            result.unwrap_or_else(|e| exit_with_error(&e));
+            if *cache_stats {
+                eprintln!("{}", cache.get_stats());
+            }
         }
         None => {
             panic!("No command provided")
         }
     }
 }
+
+/// Parse a fitness threshold given as METRIC:VALUE.
+fn parse_fitness(fitness_arg: &str) -> Result<(FitnessMetric, usize), String> {
+    let (metric_str, threshold_str) = fitness_arg
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid fitness '{}', expected METRIC:VALUE", fitness_arg))?;
+    let threshold = threshold_str
+        .parse()
+        .map_err(|_| format!("Invalid fitness threshold '{}'", threshold_str))?;
+    Ok((metric_str.parse()?, threshold))
+}
+
+/// Parse a strand argument, which is either a strand or @FILE[:ID] naming a FASTA
+/// file and optionally the ID of the record to use instead of the first.
+fn parse_strand(strand_arg: &str) -> Strand {
+    let Some(reference) = strand_arg.strip_prefix('@') else {
+        return Strand::try_from_string(strand_arg).unwrap_or_else(|e| exit_with_error(&e));
+    };
+
+    let (path, id) = match reference.rsplit_once(':') {
+        Some((path, id)) => (path, Some(id)),
+        None => (reference, None),
+    };
+    let records = FastaReader::read_file(path).unwrap_or_else(|e| exit_with_error(&e));
+    let record = match id {
+        Some(id) => records.into_iter().find(|r| r.id == id),
+        None => records.into_iter().next(),
+    };
+    match record {
+        Some(record) => record.strand,
+        None => exit_with_error(&format!("No matching FASTA record in {}", path)),
+    }
+}
+
+/// Print rows with every column but the last padded to its widest cell.
+fn print_table<const N: usize>(rows: &[[String; N]]) {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i + 1 == N {
+                    cell.clone()
+                } else {
+                    format!("{:<width$}", cell, width = width)
+                }
+            })
+            .collect();
+        println!("{}", cells.join("  "));
+    }
+}
+
+/// Parse a list of strands, where @FILE without an ID reads every record of a FASTA file.
+fn parse_strands(strand_arg: &str) -> Vec<Strand> {
+    match strand_arg.strip_prefix('@') {
+        Some(path) if !path.contains(':') => FastaReader::read_file(path)
+            .unwrap_or_else(|e| exit_with_error(&e))
+            .into_iter()
+            .map(|record| record.strand)
+            .collect(),
+        _ => vec![parse_strand(strand_arg)],
+    }
+}
+
+fn open_input(path: &str) -> Box<dyn BufRead> {
+    if path == "-" {
+        Box::new(std::io::stdin().lock())
+    } else {
+        let file = File::open(path)
+            .unwrap_or_else(|e| exit_with_error(&format!("Failed to open {}: {}", path, e)));
+        Box::new(BufReader::new(file))
+    }
+}
+
+fn exit_on_failures(n_failed: usize) {
+    if n_failed > 0 {
+        exit_with_error(&format!("{} records could not be processed", n_failed));
+    }
+}
+
+fn exit_with_error(message: &str) -> ! {
+    log::error!("{}", message);
+    std::process::exit(1);
+}
@@ -0,0 +1,32 @@
+//! Python bindings, built with the `python` feature. Classes wrap the types of the
+//! `typogenetics` module, and simulations return their results as dicts.
+
+mod py_enzyme;
+mod py_folder;
+mod py_rewriter;
+mod py_simulation;
+mod py_strand;
+mod py_translator;
+
+use pyo3::prelude::*;
+
+pub use py_enzyme::PyEnzyme;
+pub use py_folder::PyFolder;
+pub use py_rewriter::PyRewriter;
+pub use py_strand::PyStrand;
+pub use py_translator::PyTranslator;
+
+#[pymodule]
+fn typogenetics(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyStrand>()?;
+    module.add_class::<PyEnzyme>()?;
+    module.add_class::<PyTranslator>()?;
+    module.add_class::<PyRewriter>()?;
+    module.add_class::<PyFolder>()?;
+    module.add_function(wrap_pyfunction!(py_simulation::simulate, module)?)?;
+    module.add_function(wrap_pyfunction!(
+        py_simulation::simulate_generations,
+        module
+    )?)?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::typogenetics::Enzyme;
+
+#[pyclass(name = "Enzyme", module = "typogenetics", frozen, eq, hash)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PyEnzyme(pub Enzyme);
+
+#[pymethods]
+impl PyEnzyme {
+    /// Parse amino acids separated by '-', such as "cop-mvr-cut".
+    #[new]
+    fn new(amino_acids: &str) -> PyResult<PyEnzyme> {
+        Enzyme::try_from_string(amino_acids)
+            .map(PyEnzyme)
+            .map_err(PyValueError::new_err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Enzyme('{}')", self.0)
+    }
+}
@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+
+use crate::python::{PyEnzyme, PyStrand};
+use crate::typogenetics::Folder;
+
+#[pyclass(name = "Folder", module = "typogenetics", frozen)]
+pub struct PyFolder;
+
+#[pymethods]
+impl PyFolder {
+    /// Final orientation of the folded enzyme, one of "U", "D", "L" or "R".
+    #[staticmethod]
+    fn fold(enzyme: &PyEnzyme) -> String {
+        format!("{:?}", Folder::fold(&enzyme.0))
+    }
+
+    /// Base the folded enzyme binds to.
+    #[staticmethod]
+    fn get_binding_affinity(enzyme: &PyEnzyme) -> String {
+        Folder::get_binding_affinity(Folder::fold(&enzyme.0)).to_string()
+    }
+
+    /// Position the enzyme binds to in the strand, or None if it cannot bind.
+    #[staticmethod]
+    fn get_binding_site(enzyme: &PyEnzyme, strand: &PyStrand) -> Option<usize> {
+        Folder::get_binding_site(&enzyme.0, &strand.0)
+    }
+}
@@ -0,0 +1,18 @@
+use pyo3::prelude::*;
+
+use crate::python::{PyEnzyme, PyStrand};
+use crate::typogenetics::Rewriter;
+
+#[pyclass(name = "Rewriter", module = "typogenetics", frozen)]
+pub struct PyRewriter;
+
+#[pymethods]
+impl PyRewriter {
+    #[staticmethod]
+    fn rewrite(enzyme: &PyEnzyme, strand: &PyStrand) -> Vec<PyStrand> {
+        Rewriter::rewrite(&enzyme.0, &strand.0)
+            .into_iter()
+            .map(PyStrand)
+            .collect()
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::Duration;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::analysis::Reaction;
+use crate::python::{PyEnzyme, PyStrand};
+use crate::search::{
+    GenerationLimits, Limit, LimitPolicy, ResourceLimits, RewriteCache, SearchAlgos, SearchResult,
+    Simulation, StopCondition,
+};
+use crate::typogenetics::{Motif, Strand};
+
+/// Run a random simulation, see `Simulation`. Limits, stop conditions and the cache
+/// capacity match the flags of `typogenetics simulate`, with `stop_fitness` a tuple of
+/// a metric name and a threshold. Returns a dict of the discovered strands, in the order
+/// they were found, and statistics of the run.
+#[pyfunction]
+#[pyo3(signature = (
+    strands,
+    iterations = 100_000,
+    seed = 2,
+    *,
+    max_strand_len = None,
+    max_population = None,
+    max_total_bases = None,
+    time_limit = None,
+    max_rewrites = None,
+    limit_policy = "drop",
+    stop_at = None,
+    stop_pattern = None,
+    stop_self_replicator = false,
+    stop_stagnant = None,
+    stop_fitness = None,
+    cache_capacity = 0,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn simulate<'py>(
+    py: Python<'py>,
+    strands: Vec<PyStrand>,
+    iterations: usize,
+    seed: u64,
+    max_strand_len: Option<usize>,
+    max_population: Option<usize>,
+    max_total_bases: Option<usize>,
+    time_limit: Option<f64>,
+    max_rewrites: Option<usize>,
+    limit_policy: &str,
+    stop_at: Option<PyStrand>,
+    stop_pattern: Option<String>,
+    stop_self_replicator: bool,
+    stop_stagnant: Option<usize>,
+    stop_fitness: Option<(String, usize)>,
+    cache_capacity: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    let limits = ResourceLimits {
+        max_strand_len,
+        max_population,
+        max_total_bases,
+        time_limit: time_limit.map(Duration::from_secs_f64),
+        max_rewrites,
+        policy: limit_policy
+            .parse::<LimitPolicy>()
+            .map_err(PyValueError::new_err)?,
+    };
+    let mut stop_conditions = Vec::new();
+    if let Some(target) = stop_at {
+        stop_conditions.push(StopCondition::Target(target.0));
+    }
+    if let Some(pattern) = stop_pattern {
+        let motif = Motif::try_from_string(&pattern).map_err(PyValueError::new_err)?;
+        stop_conditions.push(StopCondition::Pattern(motif));
+    }
+    if stop_self_replicator {
+        stop_conditions.push(StopCondition::SelfReplicator);
+    }
+    if let Some(n) = stop_stagnant {
+        stop_conditions.push(StopCondition::Stagnation(n));
+    }
+    if let Some((metric, threshold)) = stop_fitness {
+        let metric = metric.parse().map_err(PyValueError::new_err)?;
+        stop_conditions.push(StopCondition::Fitness { metric, threshold });
+    }
+    let init_strands = to_strands(strands);
+
+    let result = py.allow_threads(|| {
+        let mut builder = Simulation::builder(&init_strands)
+            .iterations(iterations)
+            .seed(seed)
+            .limits(limits)
+            .cache_capacity(cache_capacity);
+        for condition in stop_conditions {
+            builder = builder.stop_when(condition);
+        }
+        builder.build().map(Simulation::run)
+    });
+    result_to_dict(py, result.map_err(PyValueError::new_err)?)
+}
+
+/// Run a generational simulation, see `SearchAlgos::generational`, caching up to
+/// `cache_capacity` translations and rewrites. Returns a dict of the strands, in the
+/// order they were found, and a summary of each generation.
+#[pyfunction]
+#[pyo3(signature = (
+    strands,
+    max_generations,
+    max_strands = 10_000,
+    max_strand_len = 100,
+    *,
+    cache_capacity = 0,
+))]
+pub fn simulate_generations<'py>(
+    py: Python<'py>,
+    strands: Vec<PyStrand>,
+    max_generations: usize,
+    max_strands: usize,
+    max_strand_len: usize,
+    cache_capacity: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    let init_strands = to_strands(strands);
+    if init_strands.is_empty() {
+        return Err(PyValueError::new_err(
+            "Simulation needs at least one initial strand",
+        ));
+    }
+    let limits = GenerationLimits::new(max_generations, max_strands, max_strand_len);
+    let mut summaries = Vec::new();
+    let strands = py.allow_threads(|| {
+        SearchAlgos::run_generations(
+            &init_strands,
+            limits,
+            &mut RewriteCache::new(cache_capacity),
+            |summary| summaries.push(*summary),
+            |_, _, _, _| {},
+        )
+    });
+
+    let generations = summaries
+        .iter()
+        .map(|summary| {
+            let dict = PyDict::new(py);
+            dict.set_item("generation", summary.generation)?;
+            dict.set_item("n_rewrites", summary.n_rewrites)?;
+            dict.set_item("n_new", summary.n_new)?;
+            dict.set_item("n_strands", summary.n_strands)?;
+            dict.set_item("n_too_long", summary.n_too_long)?;
+            dict.set_item("n_dropped", summary.n_dropped)?;
+            dict.set_item("n_evicted", summary.n_evicted)?;
+            Ok(dict)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let dict = PyDict::new(py);
+    dict.set_item("strands", from_strands(strands))?;
+    dict.set_item("generations", generations)?;
+    Ok(dict)
+}
+
+fn result_to_dict(py: Python<'_>, result: SearchResult) -> PyResult<Bound<'_, PyDict>> {
+    let limit_counts = PyDict::new(py);
+    for limit in Limit::ALL {
+        limit_counts.set_item(limit.to_string(), result.limits.get_count(limit))?;
+    }
+    let stop = match result.stop {
+        Some(stop) => {
+            let dict = PyDict::new(py);
+            dict.set_item("condition", stop.condition.to_string())?;
+            dict.set_item("iteration", stop.iteration)?;
+            dict.set_item("strand", stop.strand.map(PyStrand))?;
+            let reaction = stop
+                .reaction
+                .map(|reaction| reaction_to_dict(py, reaction))
+                .transpose()?;
+            dict.set_item("reaction", reaction)?;
+            Some(dict)
+        }
+        None => None,
+    };
+
+    let dict = PyDict::new(py);
+    dict.set_item("strands", from_strands(result.strands))?;
+    dict.set_item("n_iterations", result.n_iterations)?;
+    dict.set_item("n_rewrites", result.n_rewrites)?;
+    dict.set_item("elapsed_seconds", result.elapsed.as_secs_f64())?;
+    dict.set_item("limit_counts", limit_counts)?;
+    dict.set_item("n_evicted", result.limits.get_n_evicted())?;
+    dict.set_item(
+        "stopped_by_limit",
+        result.limits.stopped_by.map(|limit| limit.to_string()),
+    )?;
+    dict.set_item("stop", stop)?;
+    Ok(dict)
+}
+
+fn reaction_to_dict(py: Python<'_>, reaction: Reaction) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("catalyst", PyStrand(reaction.catalyst))?;
+    dict.set_item("enzyme", PyEnzyme(reaction.enzyme))?;
+    dict.set_item("reactant", PyStrand(reaction.reactant))?;
+    dict.set_item("products", from_strands(reaction.products))?;
+    Ok(dict)
+}
+
+fn to_strands(strands: Vec<PyStrand>) -> Vec<Strand> {
+    strands.into_iter().map(|strand| strand.0).collect()
+}
+
+fn from_strands(strands: Vec<Strand>) -> Vec<PyStrand> {
+    strands.into_iter().map(PyStrand).collect()
+}
@@ -0,0 +1,30 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::typogenetics::Strand;
+
+#[pyclass(name = "Strand", module = "typogenetics", frozen, eq, hash)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PyStrand(pub Strand);
+
+#[pymethods]
+impl PyStrand {
+    #[new]
+    fn new(bases: &str) -> PyResult<PyStrand> {
+        Strand::try_from_string(bases)
+            .map(PyStrand)
+            .map_err(PyValueError::new_err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Strand('{}')", self.0)
+    }
+}
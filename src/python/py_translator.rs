@@ -0,0 +1,18 @@
+use pyo3::prelude::*;
+
+use crate::python::{PyEnzyme, PyStrand};
+use crate::typogenetics::Translator;
+
+#[pyclass(name = "Translator", module = "typogenetics", frozen)]
+pub struct PyTranslator;
+
+#[pymethods]
+impl PyTranslator {
+    #[staticmethod]
+    fn translate(strand: &PyStrand) -> Vec<PyEnzyme> {
+        Translator::translate(&strand.0)
+            .into_iter()
+            .map(PyEnzyme)
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::analysis::ReplicationClass;
+use crate::query::{Comparison, FilterParser};
+use crate::search::FitnessMetric;
+use crate::typogenetics::{Base, Folder, Motif, Strand, Translator};
+
+/// A predicate over strands, parsed from expressions such as
+/// `len > 10 and contains("TAG") and binds(G)`, see `FilterParser` for the syntax.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Filter {
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+    Not(Box<Filter>),
+    /// A numeric property of the strand compared to a number.
+    Compare {
+        metric: FitnessMetric,
+        comparison: Comparison,
+        value: usize,
+    },
+    /// The strand contains the motif.
+    Contains(Motif),
+    /// An enzyme coded by the strand binds to the base.
+    Binds(Base),
+    /// The strand is of the replication class.
+    Class(ReplicationClass),
+}
+
+impl Filter {
+    pub fn parse(filter_str: &str) -> Result<Filter, String> {
+        FilterParser::parse(filter_str)
+    }
+
+    pub fn matches(&self, strand: &Strand) -> bool {
+        match self {
+            Filter::And(left, right) => left.matches(strand) && right.matches(strand),
+            Filter::Or(left, right) => left.matches(strand) || right.matches(strand),
+            Filter::Not(filter) => !filter.matches(strand),
+            Filter::Compare {
+                metric,
+                comparison,
+                value,
+            } => comparison.compare(metric.evaluate(strand), *value),
+            Filter::Contains(motif) => strand.contains_motif(motif),
+            Filter::Binds(base) => Translator::translate(strand)
+                .iter()
+                .any(|enzyme| Folder::get_binding_affinity(Folder::fold(enzyme)) == *base),
+            Filter::Class(class) => ReplicationClass::of(strand) == *class,
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(filter_str: &str) -> Result<Filter, String> {
+        Filter::parse(filter_str)
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Filter::And(left, right) => write!(f, "({} and {})", left, right),
+            Filter::Or(left, right) => write!(f, "({} or {})", left, right),
+            Filter::Not(filter) => write!(f, "not {}", filter),
+            Filter::Compare {
+                metric,
+                comparison,
+                value,
+            } => write!(f, "{} {} {}", metric, comparison, value),
+            Filter::Contains(motif) => write!(f, "contains(\"{}\")", motif),
+            Filter::Binds(base) => write!(f, "binds({})", base),
+            Filter::Class(class) => write!(f, "class({})", class),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::analysis::ReplicationClass;
+use crate::query::{Comparison, Filter};
+use crate::search::FitnessMetric;
+use crate::typogenetics::{Base, Motif};
+
+/// Parses filter expressions. A filter combines predicates with `and`, `or`, `not` and
+/// parentheses, `not` binding tightest and `or` loosest:
+///
+/// - `len`, `length`, `enzymes` or `self-products` compared to a number with `<`, `<=`,
+///   `>`, `>=`, `==` or `!=`, for example `len > 10`
+/// - `contains(MOTIF)`, where the motif may be quoted and may use R, Y and N
+/// - `binds(BASE)`, true if any enzyme the strand codes for binds to the base
+/// - `class(CLASS)`, one of inert, active or self-replicator
+pub struct FilterParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Token {
+    Word(String),
+    Number(usize),
+    Quoted(String),
+    Op(Comparison),
+    Open,
+    Close,
+}
+
+impl FilterParser {
+    pub fn parse(filter_str: &str) -> Result<Filter, String> {
+        let tokens = Self::tokenize(filter_str)
+            .map_err(|e| format!("Invalid filter '{}', {}", filter_str, e))?;
+        let mut parser = FilterParser { tokens, pos: 0 };
+        let filter = parser
+            .parse_or()
+            .and_then(|filter| match parser.peek() {
+                None => Ok(filter),
+                Some(token) => Err(format!("unexpected {}", Self::describe(token))),
+            })
+            .map_err(|e| format!("Invalid filter '{}', {}", filter_str, e))?;
+
+        Ok(filter)
+    }
+
+    fn tokenize(filter_str: &str) -> Result<Vec<Token>, String> {
+        let chars: Vec<char> = filter_str.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if c.is_whitespace() {
+                i += 1;
+            } else if c == '(' {
+                tokens.push(Token::Open);
+                i += 1;
+            } else if c == ')' {
+                tokens.push(Token::Close);
+                i += 1;
+            } else if c == '"' {
+                let start = i + 1;
+                let end = (start..chars.len())
+                    .find(|&j| chars[j] == '"')
+                    .ok_or_else(|| format!("unterminated string at position {}", i + 1))?;
+                tokens.push(Token::Quoted(chars[start..end].iter().collect()));
+                i = end + 1;
+            } else if "<>=!".contains(c) {
+                let has_eq = chars.get(i + 1) == Some(&'=');
+                let op = match (c, has_eq) {
+                    ('<', false) => Comparison::Lt,
+                    ('<', true) => Comparison::Le,
+                    ('>', false) => Comparison::Gt,
+                    ('>', true) => Comparison::Ge,
+                    ('=', _) => Comparison::Eq,
+                    ('!', true) => Comparison::Ne,
+                    _ => return Err(format!("unexpected '{}' at position {}", c, i + 1)),
+                };
+                tokens.push(Token::Op(op));
+                i += if has_eq { 2 } else { 1 };
+            } else if c.is_ascii_digit() {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let digits: String = chars[start..i].iter().collect();
+                let number = digits
+                    .parse()
+                    .map_err(|_| format!("number {} is too large", digits))?;
+                tokens.push(Token::Number(number));
+            } else if c.is_ascii_alphabetic() {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '-') {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            } else {
+                return Err(format!("unexpected '{}' at position {}", c, i + 1));
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    fn parse_or(&mut self) -> Result<Filter, String> {
+        let mut filter = self.parse_and()?;
+        while self.eat_keyword("or") {
+            filter = Filter::Or(Box::new(filter), Box::new(self.parse_and()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_and(&mut self) -> Result<Filter, String> {
+        let mut filter = self.parse_not()?;
+        while self.eat_keyword("and") {
+            filter = Filter::And(Box::new(filter), Box::new(self.parse_not()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_not(&mut self) -> Result<Filter, String> {
+        if self.eat_keyword("not") {
+            return Ok(Filter::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_predicate()
+    }
+
+    fn parse_predicate(&mut self) -> Result<Filter, String> {
+        match self.next() {
+            Some(Token::Open) => {
+                let filter = self.parse_or()?;
+                self.expect(Token::Close)?;
+                Ok(filter)
+            }
+            Some(Token::Word(word)) => match word.as_str() {
+                "contains" => {
+                    let motif = Motif::try_from_string(&self.parse_argument()?)?;
+                    Ok(Filter::Contains(motif))
+                }
+                "binds" => {
+                    let base_str = self.parse_argument()?;
+                    let base = Base::try_from_string(&base_str).ok_or_else(|| {
+                        format!("invalid base '{}', expected A, C, G or T", base_str)
+                    })?;
+                    Ok(Filter::Binds(base))
+                }
+                "class" => {
+                    let class: ReplicationClass = self.parse_argument()?.parse()?;
+                    Ok(Filter::Class(class))
+                }
+                _ => {
+                    let metric = match word.as_str() {
+                        "len" => FitnessMetric::Length,
+                        _ => word.parse().map_err(|_| {
+                            format!(
+                                "unknown predicate '{}', expected len, enzymes, \
+                                 self-products, contains, binds or class",
+                                word
+                            )
+                        })?,
+                    };
+                    let comparison = match self.next() {
+                        Some(Token::Op(comparison)) => comparison,
+                        token => return Err(Self::expected("a comparison", token.as_ref())),
+                    };
+                    let value = match self.next() {
+                        Some(Token::Number(value)) => value,
+                        token => return Err(Self::expected("a number", token.as_ref())),
+                    };
+                    Ok(Filter::Compare {
+                        metric,
+                        comparison,
+                        value,
+                    })
+                }
+            },
+            token => Err(Self::expected("a predicate", token.as_ref())),
+        }
+    }
+
+    /// The single argument of a predicate call, either a bare word or a quoted string.
+    fn parse_argument(&mut self) -> Result<String, String> {
+        self.expect(Token::Open)?;
+        let argument = match self.next() {
+            Some(Token::Word(word)) => word,
+            Some(Token::Quoted(quoted)) => quoted,
+            token => return Err(Self::expected("an argument", token.as_ref())),
+        };
+        self.expect(Token::Close)?;
+        Ok(argument)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Word(word)) if word == keyword) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            token => Err(Self::expected(&Self::describe(&expected), token.as_ref())),
+        }
+    }
+
+    fn expected(what: &str, found: Option<&Token>) -> String {
+        match found {
+            Some(token) => format!("expected {}, found {}", what, Self::describe(token)),
+            None => format!("expected {}, found end of filter", what),
+        }
+    }
+
+    fn describe(token: &Token) -> String {
+        match token {
+            Token::Word(word) => format!("'{}'", word),
+            Token::Number(number) => format!("'{}'", number),
+            Token::Quoted(quoted) => format!("\"{}\"", quoted),
+            Token::Op(comparison) => format!("'{}'", comparison),
+            Token::Open => String::from("'('"),
+            Token::Close => String::from("')'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(filter_str: &str) -> String {
+        FilterParser::parse(filter_str).unwrap().to_string()
+    }
+
+    fn error(filter_str: &str) -> String {
+        FilterParser::parse(filter_str).unwrap_err()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            parsed("len > 3 or len < 2 and enzymes == 1"),
+            "(length > 3 or (length < 2 and enzymes == 1))"
+        );
+        assert_eq!(
+            parsed("len > 3 and len < 9 or enzymes >= 2"),
+            "((length > 3 and length < 9) or enzymes >= 2)"
+        );
+    }
+
+    #[test]
+    fn not_binds_tightest() {
+        assert_eq!(
+            parsed("not binds(G) and class(inert)"),
+            "(not binds(G) and class(inert))"
+        );
+        assert_eq!(
+            parsed("not (binds(G) or len != 4)"),
+            "not (binds(G) or length != 4)"
+        );
+        assert_eq!(parsed("not not contains(TRY)"), "not not contains(\"TRY\")");
+    }
+
+    #[test]
+    fn operators_chain_to_the_left() {
+        assert_eq!(
+            parsed("len > 1 or len > 2 or len > 3"),
+            "((length > 1 or length > 2) or length > 3)"
+        );
+    }
+
+    #[test]
+    fn parses_quoted_arguments_and_metric_names() {
+        assert_eq!(
+            parsed("contains(\"GGN\") and self-products <= 2 and length = 5"),
+            "((contains(\"GGN\") and self-products <= 2) and length == 5)"
+        );
+    }
+
+    #[test]
+    fn reports_unexpected_tokens() {
+        assert_eq!(
+            error("len >> 3"),
+            "Invalid filter 'len >> 3', expected a number, found '>'"
+        );
+        assert_eq!(
+            error("len > 3 len"),
+            "Invalid filter 'len > 3 len', unexpected 'len'"
+        );
+        assert_eq!(
+            error("len ! 3"),
+            "Invalid filter 'len ! 3', unexpected '!' at position 5"
+        );
+        assert_eq!(
+            error("len > 3 &"),
+            "Invalid filter 'len > 3 &', unexpected '&' at position 9"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_predicates() {
+        assert_eq!(
+            error("foo > 3"),
+            "Invalid filter 'foo > 3', unknown predicate 'foo', expected len, enzymes, \
+             self-products, contains, binds or class"
+        );
+    }
+
+    #[test]
+    fn reports_missing_tokens() {
+        assert_eq!(
+            error("(len > 3"),
+            "Invalid filter '(len > 3', expected ')', found end of filter"
+        );
+        assert_eq!(
+            error("len >"),
+            "Invalid filter 'len >', expected a number, found end of filter"
+        );
+        assert_eq!(
+            error("len > 3 and"),
+            "Invalid filter 'len > 3 and', expected a predicate, found end of filter"
+        );
+        assert_eq!(
+            error("contains(\"GG"),
+            "Invalid filter 'contains(\"GG', unterminated string at position 10"
+        );
+    }
+
+    #[test]
+    fn reports_invalid_arguments() {
+        assert_eq!(
+            error("binds(U)"),
+            "Invalid filter 'binds(U)', invalid base 'U', expected A, C, G or T"
+        );
+        assert_eq!(
+            error("binds()"),
+            "Invalid filter 'binds()', expected an argument, found ')'"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod comparison;
+mod filter;
+mod filter_parser;
+
+pub use comparison::Comparison;
+pub use filter::Filter;
+pub use filter_parser::FilterParser;
@@ -0,0 +1,141 @@
+/// A line of REPL input, see `Command::HELP` for the syntax of each command.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    DefineStrand {
+        name: String,
+        strand_str: String,
+    },
+    DefineEnzyme {
+        name: String,
+        enzyme_str: String,
+    },
+    Translate {
+        strand_str: String,
+    },
+    Rewrite {
+        enzyme_str: String,
+        strand_str: String,
+    },
+    Apply {
+        source_str: String,
+        target_str: String,
+    },
+    Add {
+        strand_strs: Vec<String>,
+    },
+    Soup,
+    Search {
+        pattern: String,
+    },
+    Names,
+    Undo,
+    Redo,
+    Save {
+        path: String,
+    },
+    Load {
+        path: String,
+    },
+    Import {
+        path: String,
+    },
+    Export {
+        path: String,
+    },
+    Help,
+    Quit,
+}
+
+impl Command {
+    pub const HELP: &'static str = "\
+strand NAME BASES        define a named strand
+enzyme NAME ENZYME       define a named enzyme
+translate STRAND         translate a strand into enzymes
+rewrite ENZYME STRAND    rewrite a strand using an enzyme
+apply STRAND to STRAND   apply every enzyme of one strand to another
+add [STRAND...]          add strands, or the last products, to the soup
+soup                     list the strands in the soup
+search MOTIF             list soup strands containing bases, R, Y or N wildcards
+names                    list named strands and enzymes
+undo                     undo the last change to the session
+redo                     redo the last undone change to the session
+save FILE                save the session to a file
+load FILE                replace the session with one loaded from a file
+import FILE              name and add to the soup every strand in a FASTA file
+export FILE              write the soup to a FASTA file
+help                     show available commands
+quit                     leave the REPL";
+
+    /// Parse a line of input. Blank lines and lines starting with `#` parse to `None`.
+    pub fn parse(line: &str) -> Result<Option<Command>, String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let tokens: Vec<String> = line.split_whitespace().map(String::from).collect();
+        let args = &tokens[1..];
+
+        let command = match (tokens[0].as_str(), args) {
+            ("strand", [name, strand_str]) => Command::DefineStrand {
+                name: Self::parse_name(name)?,
+                strand_str: strand_str.clone(),
+            },
+            ("enzyme", [name, enzyme_str]) => Command::DefineEnzyme {
+                name: Self::parse_name(name)?,
+                enzyme_str: enzyme_str.clone(),
+            },
+            ("translate", [strand_str]) => Command::Translate {
+                strand_str: strand_str.clone(),
+            },
+            ("rewrite", [enzyme_str, strand_str]) => Command::Rewrite {
+                enzyme_str: enzyme_str.clone(),
+                strand_str: strand_str.clone(),
+            },
+            ("apply", [source_str, to, target_str]) if to == "to" => Command::Apply {
+                source_str: source_str.clone(),
+                target_str: target_str.clone(),
+            },
+            ("add", strand_strs) => Command::Add {
+                strand_strs: strand_strs.to_vec(),
+            },
+            ("soup", []) => Command::Soup,
+            ("search", [pattern]) => Command::Search {
+                pattern: pattern.clone(),
+            },
+            ("names", []) => Command::Names,
+            ("undo", []) => Command::Undo,
+            ("redo", []) => Command::Redo,
+            ("save", [path]) => Command::Save { path: path.clone() },
+            ("load", [path]) => Command::Load { path: path.clone() },
+            ("import", [path]) => Command::Import { path: path.clone() },
+            ("export", [path]) => Command::Export { path: path.clone() },
+            ("help", []) => Command::Help,
+            ("quit", []) | ("exit", []) => Command::Quit,
+            (
+                "strand" | "enzyme" | "translate" | "rewrite" | "apply" | "soup" | "search"
+                | "names" | "undo" | "redo" | "save" | "load" | "import" | "export" | "help"
+                | "quit" | "exit",
+                _,
+            ) => return Err(format!("Wrong arguments for '{}', see 'help'", tokens[0])),
+            _ => return Err(format!("Unknown command '{}', see 'help'", tokens[0])),
+        };
+
+        Ok(Some(command))
+    }
+
+    /// Names start with a lowercase letter so they never look like a strand.
+    fn parse_name(name: &str) -> Result<String, String> {
+        let mut chars = name.chars();
+        let valid_start = chars.next().is_some_and(|c| c.is_ascii_lowercase());
+        let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if valid_start && valid_rest {
+            Ok(String::from(name))
+        } else {
+            Err(format!(
+                "Invalid name '{}', names must start with a lowercase letter",
+                name
+            ))
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::io::{BufRead, Write};
+
+use crate::repl::{Command, Session};
+use crate::typogenetics::{Motif, Rewriter, Strand, Translator};
+
+/// Reads commands line by line and executes them against a session.
+pub struct Interpreter;
+
+impl Interpreter {
+    pub fn run(
+        session: &mut Session,
+        input: &mut impl BufRead,
+        output: &mut impl Write,
+        prompt: bool,
+    ) -> std::io::Result<()> {
+        let mut line = String::new();
+        loop {
+            if prompt {
+                write!(output, "> ")?;
+                output.flush()?;
+            }
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            let result = Command::parse(&line).and_then(|command| match command {
+                Some(command) => Self::execute(session, command, output),
+                None => Ok(true),
+            });
+            match result {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(message) => writeln!(output, "error: {}", message)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute a single command. Returns whether the REPL should keep running.
+    pub fn execute(
+        session: &mut Session,
+        command: Command,
+        output: &mut impl Write,
+    ) -> Result<bool, String> {
+        Self::execute_inner(session, command, output).map_err(|e| match e {
+            ExecuteError::Message(message) => message,
+            ExecuteError::Io(e) => format!("Failed to write output: {}", e),
+        })
+    }
+
+    fn execute_inner(
+        session: &mut Session,
+        command: Command,
+        output: &mut impl Write,
+    ) -> Result<bool, ExecuteError> {
+        match command {
+            Command::DefineStrand { name, strand_str } => {
+                let strand = Strand::try_from_string(&strand_str)?;
+                session.define_strand(&name, strand);
+            }
+            Command::DefineEnzyme { name, enzyme_str } => {
+                let enzyme = session.resolve_enzyme(&enzyme_str)?;
+                session.define_enzyme(&name, enzyme);
+            }
+            Command::Translate { strand_str } => {
+                let strand = session.resolve_strand(&strand_str)?;
+                let enzymes = Translator::translate(&strand);
+                if enzymes.is_empty() {
+                    writeln!(output, "No enzymes")?;
+                }
+                for enzyme in enzymes {
+                    writeln!(output, "- {}", enzyme)?;
+                }
+            }
+            Command::Rewrite {
+                enzyme_str,
+                strand_str,
+            } => {
+                let enzyme = session.resolve_enzyme(&enzyme_str)?;
+                let strand = session.resolve_strand(&strand_str)?;
+                let products = Rewriter::rewrite(&enzyme, &strand);
+                for product in &products {
+                    writeln!(output, "- {}", product)?;
+                }
+                session.set_last_products(products);
+            }
+            Command::Apply {
+                source_str,
+                target_str,
+            } => {
+                let source = session.resolve_strand(&source_str)?;
+                let target = session.resolve_strand(&target_str)?;
+                let enzymes = Translator::translate(&source);
+                if enzymes.is_empty() {
+                    writeln!(output, "No enzymes")?;
+                }
+                let mut all_products = Vec::new();
+                for enzyme in enzymes {
+                    let products = Rewriter::rewrite(&enzyme, &target);
+                    let products_str = products
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ");
+                    writeln!(output, "- {}: {}", enzyme, products_str)?;
+                    all_products.extend(products);
+                }
+                session.set_last_products(all_products);
+            }
+            Command::Add { strand_strs } => {
+                let strands = if strand_strs.is_empty() {
+                    session.get_last_products().to_vec()
+                } else {
+                    strand_strs
+                        .iter()
+                        .map(|s| session.resolve_strand(s))
+                        .collect::<Result<Vec<Strand>, String>>()?
+                };
+                let n_added = session.add_to_soup(&strands);
+                writeln!(output, "Added {} new strands to the soup", n_added)?;
+            }
+            Command::Soup => {
+                let soup: Vec<&Strand> = session.iter_soup().collect();
+                writeln!(output, "{} strands in the soup", soup.len())?;
+                for strand in soup {
+                    writeln!(output, "- {}", strand)?;
+                }
+            }
+            Command::Search { pattern } => {
+                let matches = session.search_soup(&Motif::try_from_string(&pattern)?);
+                writeln!(output, "{} strands contain {}", matches.len(), pattern)?;
+                for strand in matches {
+                    writeln!(output, "- {}", strand)?;
+                }
+            }
+            Command::Names => {
+                for (name, strand) in session.iter_strands() {
+                    writeln!(output, "strand {} = {}", name, strand)?;
+                }
+                for (name, enzyme) in session.iter_enzymes() {
+                    writeln!(output, "enzyme {} = {}", name, enzyme)?;
+                }
+            }
+            Command::Undo => {
+                if !session.undo() {
+                    writeln!(output, "Nothing to undo")?;
+                }
+            }
+            Command::Redo => {
+                if !session.redo() {
+                    writeln!(output, "Nothing to redo")?;
+                }
+            }
+            Command::Save { path } => {
+                session.save(&path)?;
+                writeln!(output, "Saved session to {}", path)?;
+            }
+            Command::Load { path } => {
+                session.load(&path)?;
+                writeln!(output, "Loaded session from {}", path)?;
+            }
+            Command::Import { path } => {
+                let n_imported = session.import_fasta(&path)?;
+                writeln!(output, "Imported {} strands from {}", n_imported, path)?;
+            }
+            Command::Export { path } => {
+                session.export_fasta(&path)?;
+                writeln!(output, "Exported the soup to {}", path)?;
+            }
+            Command::Help => writeln!(output, "{}", Command::HELP)?,
+            Command::Quit => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+enum ExecuteError {
+    Message(String),
+    Io(std::io::Error),
+}
+
+impl From<String> for ExecuteError {
+    fn from(message: String) -> ExecuteError {
+        ExecuteError::Message(message)
+    }
+}
+
+impl From<std::io::Error> for ExecuteError {
+    fn from(e: std::io::Error) -> ExecuteError {
+        ExecuteError::Io(e)
+    }
+}
@@ -0,0 +1,7 @@
+mod command;
+mod interpreter;
+mod session;
+
+pub use command::Command;
+pub use interpreter::Interpreter;
+pub use session::Session;
@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, fs};
+
+use crate::fasta::{FastaReader, FastaRecord, FastaWriter};
+use crate::repl::Command;
+use crate::typogenetics::{Enzyme, Motif, Strand};
+
+#[derive(Clone, Default)]
+struct SessionState {
+    strands: BTreeMap<String, Strand>,
+    enzymes: BTreeMap<String, Enzyme>,
+    soup: Vec<Strand>,
+}
+
+/// Named strands and enzymes together with a working soup of strands. Every change
+/// to the session can be undone and redone.
+#[derive(Default)]
+pub struct Session {
+    state: SessionState,
+    undo_stack: Vec<SessionState>,
+    redo_stack: Vec<SessionState>,
+    last_products: Vec<Strand>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    pub fn define_strand(&mut self, name: &str, strand: Strand) {
+        self.checkpoint();
+        self.state.strands.insert(String::from(name), strand);
+    }
+
+    pub fn define_enzyme(&mut self, name: &str, enzyme: Enzyme) {
+        self.checkpoint();
+        self.state.enzymes.insert(String::from(name), enzyme);
+    }
+
+    /// Add strands to the soup, skipping any already present. Returns the number added.
+    pub fn add_to_soup(&mut self, strands: &[Strand]) -> usize {
+        let new_strands: Vec<Strand> = strands
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| !self.state.soup.contains(s) && !strands[..*i].contains(s))
+            .map(|(_, s)| s.clone())
+            .collect();
+        if !new_strands.is_empty() {
+            self.checkpoint();
+            self.state.soup.extend(new_strands.iter().cloned());
+        }
+        new_strands.len()
+    }
+
+    /// Look up a named strand, or parse the token as a strand if no name matches.
+    pub fn resolve_strand(&self, strand_str: &str) -> Result<Strand, String> {
+        match self.state.strands.get(strand_str) {
+            Some(strand) => Ok(strand.clone()),
+            None => Strand::try_from_string(strand_str),
+        }
+    }
+
+    /// Look up a named enzyme, or parse the token as an enzyme if no name matches.
+    pub fn resolve_enzyme(&self, enzyme_str: &str) -> Result<Enzyme, String> {
+        match self.state.enzymes.get(enzyme_str) {
+            Some(enzyme) => Ok(enzyme.clone()),
+            None => Enzyme::try_from_string(enzyme_str),
+        }
+    }
+
+    pub fn iter_strands(&self) -> impl Iterator<Item = (&String, &Strand)> {
+        self.state.strands.iter()
+    }
+
+    pub fn iter_enzymes(&self) -> impl Iterator<Item = (&String, &Enzyme)> {
+        self.state.enzymes.iter()
+    }
+
+    pub fn iter_soup(&self) -> impl Iterator<Item = &Strand> {
+        self.state.soup.iter()
+    }
+
+    pub fn search_soup(&self, motif: &Motif) -> Vec<&Strand> {
+        self.state
+            .soup
+            .iter()
+            .filter(|s| s.contains_motif(motif))
+            .collect()
+    }
+
+    pub fn set_last_products(&mut self, products: Vec<Strand>) {
+        self.last_products = products;
+    }
+
+    pub fn get_last_products(&self) -> &[Strand] {
+        &self.last_products
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(state) => {
+                let current = std::mem::replace(&mut self.state, state);
+                self.redo_stack.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(state) => {
+                let current = std::mem::replace(&mut self.state, state);
+                self.undo_stack.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Save the session as a script of commands that rebuild it.
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let mut lines = Vec::new();
+        for (name, strand) in &self.state.strands {
+            lines.push(format!("strand {} {}", name, strand));
+        }
+        for (name, enzyme) in &self.state.enzymes {
+            lines.push(format!("enzyme {} {}", name, enzyme));
+        }
+        for strand in &self.state.soup {
+            lines.push(format!("add {}", strand));
+        }
+        let contents = lines.join("\n") + "\n";
+        fs::write(path, contents).map_err(|e| format!("Failed to save {}: {}", path, e))
+    }
+
+    /// Replace the session with one saved by `Session::save`. Loading can be undone.
+    pub fn load(&mut self, path: &str) -> Result<(), String> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("Failed to load {}: {}", path, e))?;
+
+        let mut loaded = Session::new();
+        for (line_number, line) in contents.lines().enumerate() {
+            let line_error = |e: String| format!("{}:{}: {}", path, line_number + 1, e);
+            match Command::parse(line).map_err(line_error)? {
+                None => {}
+                Some(Command::DefineStrand { name, strand_str }) => {
+                    let strand = Strand::try_from_string(&strand_str).map_err(line_error)?;
+                    loaded.state.strands.insert(name, strand);
+                }
+                Some(Command::DefineEnzyme { name, enzyme_str }) => {
+                    let enzyme = Enzyme::try_from_string(&enzyme_str).map_err(line_error)?;
+                    loaded.state.enzymes.insert(name, enzyme);
+                }
+                Some(Command::Add { strand_strs }) => {
+                    for strand_str in strand_strs {
+                        let strand = loaded.resolve_strand(&strand_str).map_err(line_error)?;
+                        if !loaded.state.soup.contains(&strand) {
+                            loaded.state.soup.push(strand);
+                        }
+                    }
+                }
+                Some(_) => {
+                    return Err(line_error(String::from(
+                        "Session files may only define strands and enzymes and add to the soup",
+                    )))
+                }
+            }
+        }
+
+        self.checkpoint();
+        self.state = loaded.state;
+        Ok(())
+    }
+
+    /// Define a named strand for every record in a FASTA file and add them all to the
+    /// soup as a single change. Returns the number of records imported.
+    pub fn import_fasta(&mut self, path: &str) -> Result<usize, String> {
+        let records = FastaReader::read_file(path)?;
+        for record in &records {
+            if !Self::is_valid_name(&record.id) {
+                return Err(format!(
+                    "{}: FASTA ID '{}' cannot be used as a name, names must start with a lowercase letter",
+                    path, record.id
+                ));
+            }
+        }
+
+        self.checkpoint();
+        for record in &records {
+            self.state
+                .strands
+                .insert(record.id.clone(), record.strand.clone());
+            if !self.state.soup.contains(&record.strand) {
+                self.state.soup.push(record.strand.clone());
+            }
+        }
+        Ok(records.len())
+    }
+
+    /// Write the soup to a FASTA file, naming strands after their definitions where
+    /// possible.
+    pub fn export_fasta(&self, path: &str) -> Result<(), String> {
+        let records: Vec<FastaRecord> = self
+            .state
+            .soup
+            .iter()
+            .enumerate()
+            .map(|(i, strand)| {
+                let name = self
+                    .state
+                    .strands
+                    .iter()
+                    .find(|(_, s)| *s == strand)
+                    .map(|(name, _)| name.clone());
+                FastaRecord {
+                    id: name.unwrap_or_else(|| format!("soup_{}", i + 1)),
+                    description: String::new(),
+                    strand: strand.clone(),
+                }
+            })
+            .collect();
+
+        let mut file =
+            fs::File::create(path).map_err(|e| format!("Failed to export {}: {}", path, e))?;
+        FastaWriter::write(&mut file, &records)
+            .map_err(|e| format!("Failed to export {}: {}", path, e))
+    }
+
+    fn is_valid_name(name: &str) -> bool {
+        Command::parse(&format!("strand {} A", name)).is_ok()
+    }
+
+    fn checkpoint(&mut self) {
+        self.undo_stack.push(self.state.clone());
+        self.redo_stack.clear();
+    }
+}
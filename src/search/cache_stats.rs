@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+
+/// Hits and misses of a `RewriteCache`, counted separately for translations and rewrites.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CacheStats {
+    pub translation_hits: u64,
+    pub translation_misses: u64,
+    pub rewrite_hits: u64,
+    pub rewrite_misses: u64,
+}
+
+impl CacheStats {
+    /// Add the counts of another cache, such as one owned by another worker.
+    pub fn add(&mut self, other: &CacheStats) {
+        self.translation_hits += other.translation_hits;
+        self.translation_misses += other.translation_misses;
+        self.rewrite_hits += other.rewrite_hits;
+        self.rewrite_misses += other.rewrite_misses;
+    }
+
+    pub fn get_translation_hit_rate(&self) -> f64 {
+        Self::hit_rate(self.translation_hits, self.translation_misses)
+    }
+
+    pub fn get_rewrite_hit_rate(&self) -> f64 {
+        Self::hit_rate(self.rewrite_hits, self.rewrite_misses)
+    }
+
+    fn hit_rate(hits: u64, misses: u64) -> f64 {
+        if hits + misses == 0 {
+            0.0
+        } else {
+            hits as f64 / (hits + misses) as f64
+        }
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Translation cache: {} hits, {} misses ({:.1}%). Rewrite cache: {} hits, {} misses ({:.1}%)",
+            self.translation_hits,
+            self.translation_misses,
+            100.0 * self.get_translation_hit_rate(),
+            self.rewrite_hits,
+            self.rewrite_misses,
+            100.0 * self.get_rewrite_hit_rate()
+        )
+    }
+}
@@ -0,0 +1,215 @@
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+use serde_json::{json, Value};
+
+use crate::search::CheckpointState;
+use crate::typogenetics::Strand;
+
+/// Everything needed to continue a search exactly where it stopped, stored as JSON.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    /// Every strand discovered, in the order it was found.
+    pub strands: Vec<Strand>,
+    /// Iterations, or generations, completed so far.
+    pub iteration: usize,
+    pub n_rewrites: usize,
+    pub state: CheckpointState,
+}
+
+impl Checkpoint {
+    const FORMAT: &'static str = "typogenetics-checkpoint";
+    const VERSION: u64 = 1;
+
+    /// The state of a search that has not started yet.
+    pub fn start(init_strands: &[Strand], state: CheckpointState) -> Checkpoint {
+        let mut strands: Vec<Strand> = Vec::new();
+        for strand in init_strands {
+            if !strands.contains(strand) {
+                strands.push(strand.clone());
+            }
+        }
+        Checkpoint {
+            strands,
+            iteration: 0,
+            n_rewrites: 0,
+            state,
+        }
+    }
+
+    /// Write the checkpoint next to `path` and then move it over `path`, so a crash while
+    /// writing never leaves a truncated checkpoint behind.
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let tmp_path = format!("{}.tmp", path);
+        std::fs::write(&tmp_path, self.to_json().to_string())
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .map_err(|e| format!("Failed to write checkpoint {}: {}", path, e))
+    }
+
+    pub fn load(path: &str) -> Result<Checkpoint, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read checkpoint {}: {}", path, e))?;
+        let value: Value = serde_json::from_str(&text)
+            .map_err(|e| format!("Invalid checkpoint {}: {}", path, e))?;
+        Self::from_json(&value).map_err(|e| format!("Invalid checkpoint {}: {}", path, e))
+    }
+
+    fn to_json(&self) -> Value {
+        let strands: Vec<String> = self.strands.iter().map(|s| s.to_string()).collect();
+        let mut value = json!({
+            "format": Self::FORMAT,
+            "version": Self::VERSION,
+            "iteration": self.iteration,
+            "n_rewrites": self.n_rewrites,
+        });
+        match &self.state {
+            CheckpointState::Random {
+                rng,
+                last_new_iteration,
+            } => {
+                let seed: String = rng
+                    .get_seed()
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect();
+                value["mode"] = json!("random");
+                value["rng"] = json!({
+                    "seed": seed,
+                    "stream": rng.get_stream(),
+                    // Word positions are u128, too large for a JSON number
+                    "word_pos": rng.get_word_pos().to_string(),
+                });
+                value["last_new_iteration"] = json!(last_new_iteration);
+            }
+            CheckpointState::Generational { n_done } => {
+                value["mode"] = json!("generational");
+                value["n_done"] = json!(n_done);
+            }
+        }
+        value["strands"] = json!(strands);
+        value
+    }
+
+    fn from_json(value: &Value) -> Result<Checkpoint, String> {
+        if value["format"] != Self::FORMAT {
+            return Err(String::from("not a checkpoint file"));
+        }
+        if value["version"] != Self::VERSION {
+            return Err(format!("unsupported version {}", value["version"]));
+        }
+
+        let state = match value["mode"].as_str() {
+            Some("random") => CheckpointState::Random {
+                rng: Box::new(Self::rng_from_json(&value["rng"])?),
+                last_new_iteration: Self::get_usize(value, "last_new_iteration")?,
+            },
+            Some("generational") => CheckpointState::Generational {
+                n_done: Self::get_usize(value, "n_done")?,
+            },
+            _ => return Err(format!("unknown mode {}", value["mode"])),
+        };
+        let strands = value["strands"]
+            .as_array()
+            .ok_or("missing strands")?
+            .iter()
+            .map(|s| Strand::try_from_string(s.as_str().unwrap_or_default()))
+            .collect::<Result<Vec<Strand>, String>>()?;
+        if strands.is_empty() {
+            return Err(String::from("no strands"));
+        }
+
+        Ok(Checkpoint {
+            strands,
+            iteration: Self::get_usize(value, "iteration")?,
+            n_rewrites: Self::get_usize(value, "n_rewrites")?,
+            state,
+        })
+    }
+
+    fn rng_from_json(value: &Value) -> Result<ChaCha8Rng, String> {
+        let seed_str = value["seed"].as_str().ok_or("missing RNG seed")?;
+        let mut seed = [0u8; 32];
+        if seed_str.len() != 2 * seed.len() {
+            return Err(String::from("RNG seed must be 64 hex digits"));
+        }
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&seed_str[2 * i..2 * i + 2], 16)
+                .map_err(|_| "RNG seed must be 64 hex digits")?;
+        }
+        let stream = value["stream"].as_u64().ok_or("missing RNG stream")?;
+        let word_pos: u128 = value["word_pos"]
+            .as_str()
+            .and_then(|w| w.parse().ok())
+            .ok_or("missing RNG word position")?;
+
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        rng.set_stream(stream);
+        rng.set_word_pos(word_pos);
+        Ok(rng)
+    }
+
+    fn get_usize(value: &Value, key: &str) -> Result<usize, String> {
+        value[key]
+            .as_u64()
+            .map(|n| n as usize)
+            .ok_or(format!("missing {}", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_random_state() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        rng.set_word_pos(12345);
+        let checkpoint = Checkpoint {
+            strands: vec![Strand::from_string("ATAG"), Strand::from_string("CG")],
+            iteration: 40,
+            n_rewrites: 31,
+            state: CheckpointState::Random {
+                rng: Box::new(rng.clone()),
+                last_new_iteration: 17,
+            },
+        };
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        checkpoint.save(path).unwrap();
+        let loaded = Checkpoint::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(loaded.strands, checkpoint.strands);
+        assert_eq!(loaded.iteration, 40);
+        assert_eq!(loaded.n_rewrites, 31);
+        let CheckpointState::Random {
+            rng: loaded_rng,
+            last_new_iteration,
+        } = loaded.state
+        else {
+            panic!("Expected a random checkpoint");
+        };
+        assert_eq!(*loaded_rng, rng);
+        assert_eq!(last_new_iteration, 17);
+    }
+
+    #[test]
+    fn round_trips_generational_state() {
+        let checkpoint = Checkpoint::start(
+            &[Strand::from_string("ATAG")],
+            CheckpointState::Generational { n_done: 3 },
+        );
+        let loaded = Checkpoint::from_json(&checkpoint.to_json()).unwrap();
+
+        assert_eq!(loaded.strands, checkpoint.strands);
+        assert!(matches!(
+            loaded.state,
+            CheckpointState::Generational { n_done: 3 }
+        ));
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        let error = Checkpoint::from_json(&json!({"format": "other"})).unwrap_err();
+
+        assert_eq!(error, "not a checkpoint file");
+    }
+}
@@ -0,0 +1,7 @@
+/// Where and how often a search writes checkpoints.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CheckpointSchedule {
+    pub path: String,
+    /// Iterations, or generations, between checkpoints.
+    pub every: usize,
+}
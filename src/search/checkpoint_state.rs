@@ -0,0 +1,13 @@
+use rand_chacha::ChaCha8Rng;
+
+/// The part of a checkpoint specific to the search algorithm that wrote it.
+#[derive(Debug, Clone)]
+pub enum CheckpointState {
+    Random {
+        rng: Box<ChaCha8Rng>,
+        /// Iteration that discovered the latest new strand, which stagnation counts from.
+        last_new_iteration: usize,
+    },
+    /// Every pair of the first `n_done` strands has already been rewritten.
+    Generational { n_done: usize },
+}
@@ -0,0 +1,61 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::analysis::SelfApplier;
+use crate::typogenetics::{Strand, Translator};
+
+/// A score of a single strand that a search can stop on.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FitnessMetric {
+    /// Number of bases.
+    Length,
+    /// Number of enzymes the strand codes for.
+    Enzymes,
+    /// Number of distinct strands produced by applying the strand to itself.
+    SelfProducts,
+}
+
+impl FitnessMetric {
+    pub fn evaluate(self, strand: &Strand) -> usize {
+        match self {
+            FitnessMetric::Length => strand.len(),
+            FitnessMetric::Enzymes => Translator::translate(strand).len(),
+            FitnessMetric::SelfProducts => {
+                let mut products: Vec<Strand> = SelfApplier::apply(strand)
+                    .into_iter()
+                    .flat_map(|a| a.products)
+                    .collect();
+                products.sort_by_key(|p| p.to_string());
+                products.dedup();
+                products.len()
+            }
+        }
+    }
+}
+
+impl FromStr for FitnessMetric {
+    type Err = String;
+
+    fn from_str(metric_str: &str) -> Result<FitnessMetric, String> {
+        match metric_str {
+            "length" => Ok(FitnessMetric::Length),
+            "enzymes" => Ok(FitnessMetric::Enzymes),
+            "self-products" => Ok(FitnessMetric::SelfProducts),
+            _ => Err(format!(
+                "Invalid fitness metric '{}', expected length, enzymes or self-products",
+                metric_str
+            )),
+        }
+    }
+}
+
+impl Display for FitnessMetric {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            FitnessMetric::Length => "length",
+            FitnessMetric::Enzymes => "enzymes",
+            FitnessMetric::SelfProducts => "self-products",
+        };
+        write!(f, "{}", name)
+    }
+}
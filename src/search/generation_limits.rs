@@ -0,0 +1,29 @@
+use crate::search::{LimitPolicy, ResourceLimits};
+
+/// Caps that keep a generational search finite, with resource limits applied to products
+/// as in a random search.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GenerationLimits {
+    pub max_generations: usize,
+    pub resources: ResourceLimits,
+}
+
+impl GenerationLimits {
+    /// Drop products longer than `max_strand_len` and products found once the set holds
+    /// `max_strands` strands.
+    pub fn new(
+        max_generations: usize,
+        max_strands: usize,
+        max_strand_len: usize,
+    ) -> GenerationLimits {
+        GenerationLimits {
+            max_generations,
+            resources: ResourceLimits {
+                max_strand_len: Some(max_strand_len),
+                max_population: Some(max_strands),
+                policy: LimitPolicy::Drop,
+                ..ResourceLimits::unlimited()
+            },
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+/// What happened during one generation of a generational search.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GenerationSummary {
+    pub generation: usize,
+    pub n_rewrites: usize,
+    pub n_new: usize,
+    pub n_strands: usize,
+    pub n_too_long: usize,
+    pub n_dropped: usize,
+    pub n_evicted: usize,
+}
+
+impl Display for GenerationSummary {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Generation {}: {} rewrites, {} new strands, {} strands in total",
+            self.generation, self.n_rewrites, self.n_new, self.n_strands
+        )?;
+        if self.n_too_long > 0 {
+            write!(f, ", {} products too long", self.n_too_long)?;
+        }
+        if self.n_dropped > 0 {
+            write!(
+                f,
+                ", {} products dropped at the set size cap",
+                self.n_dropped
+            )?;
+        }
+        if self.n_evicted > 0 {
+            write!(f, ", {} strands evicted", self.n_evicted)?;
+        }
+        Ok(())
+    }
+}
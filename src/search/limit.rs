@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+
+/// A resource limit of a simulation, see `ResourceLimits`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Limit {
+    StrandLength,
+    Population,
+    TotalBases,
+    WallClock,
+    Rewrites,
+}
+
+impl Limit {
+    pub const ALL: [Limit; 5] = [
+        Limit::StrandLength,
+        Limit::Population,
+        Limit::TotalBases,
+        Limit::WallClock,
+        Limit::Rewrites,
+    ];
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            Limit::StrandLength => "strand length limit",
+            Limit::Population => "population limit",
+            Limit::TotalBases => "total bases limit",
+            Limit::WallClock => "time limit",
+            Limit::Rewrites => "rewrite limit",
+        };
+        write!(f, "{}", name)
+    }
+}
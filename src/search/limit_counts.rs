@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+use crate::search::{Limit, LimitPolicy};
+
+/// How often each limit of a simulation fired, and which limit ended it, if any.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct LimitCounts {
+    counts: [usize; Limit::ALL.len()],
+    n_evicted: usize,
+    pub policy: Option<LimitPolicy>,
+    pub stopped_by: Option<Limit>,
+    pub stopped_at: Option<usize>,
+}
+
+impl LimitCounts {
+    pub fn new(policy: LimitPolicy) -> LimitCounts {
+        LimitCounts {
+            policy: Some(policy),
+            ..LimitCounts::default()
+        }
+    }
+
+    pub fn fire(&mut self, limit: Limit) {
+        self.counts[limit as usize] += 1;
+    }
+
+    /// Count a strand evicted to make room for a product.
+    pub fn evict(&mut self) {
+        self.n_evicted += 1;
+    }
+
+    pub fn stop(&mut self, limit: Limit, iteration: usize) {
+        self.stopped_by = Some(limit);
+        self.stopped_at = Some(iteration);
+    }
+
+    pub fn get_count(&self, limit: Limit) -> usize {
+        self.counts[limit as usize]
+    }
+
+    pub fn get_n_evicted(&self) -> usize {
+        self.n_evicted
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|&count| count == 0)
+    }
+}
+
+impl Display for LimitCounts {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut lines = Vec::new();
+        for limit in Limit::ALL {
+            let count = self.get_count(limit);
+            if count == 0 {
+                continue;
+            }
+            let action = match (limit, self.policy) {
+                (Limit::WallClock | Limit::Rewrites, _) => "stopped the run",
+                (_, Some(LimitPolicy::Evict)) if limit != Limit::StrandLength => "evicted strands",
+                (_, Some(LimitPolicy::Stop)) => "stopped the run",
+                _ => "dropped products",
+            };
+            let times = if count == 1 { "time" } else { "times" };
+            lines.push(format!("Hit the {} {} {}, {}", limit, count, times, action));
+        }
+        if self.n_evicted > 0 {
+            lines.push(format!("Evicted {} strands", self.n_evicted));
+        }
+        if let (Some(limit), Some(iteration)) = (self.stopped_by, self.stopped_at) {
+            lines.push(format!("Stopped at iteration {} by {}", iteration, limit));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
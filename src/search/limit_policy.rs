@@ -0,0 +1,29 @@
+use std::str::FromStr;
+
+/// What a simulation does with a product that would break a limit on the soup.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LimitPolicy {
+    /// Discard the product.
+    Drop,
+    /// Discard the product and end the run.
+    Stop,
+    /// Remove the oldest strands, except the initial one, until the product fits. Products
+    /// that are too long are dropped.
+    Evict,
+}
+
+impl FromStr for LimitPolicy {
+    type Err = String;
+
+    fn from_str(policy_str: &str) -> Result<LimitPolicy, String> {
+        match policy_str {
+            "drop" => Ok(LimitPolicy::Drop),
+            "stop" => Ok(LimitPolicy::Stop),
+            "evict" => Ok(LimitPolicy::Evict),
+            _ => Err(format!(
+                "Invalid limit policy '{}', expected drop, stop or evict",
+                policy_str
+            )),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+
+/// Mean, sample standard deviation and 95% confidence interval of the mean of a metric
+/// across replicate runs.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MetricSummary {
+    pub mean: f64,
+    pub sd: f64,
+    pub ci_low: f64,
+    pub ci_high: f64,
+}
+
+impl MetricSummary {
+    /// Two-sided 95% critical values of Student's t for 1 to 30 degrees of freedom.
+    const T_95: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    const Z_95: f64 = 1.960;
+
+    /// Summarize a metric, with a t-based interval. A single value has no spread, so its
+    /// interval is the value itself.
+    pub fn from_values(values: &[f64]) -> MetricSummary {
+        let n = values.len();
+        if n == 0 {
+            return MetricSummary {
+                mean: f64::NAN,
+                sd: f64::NAN,
+                ci_low: f64::NAN,
+                ci_high: f64::NAN,
+            };
+        }
+        let mean = values.iter().sum::<f64>() / n as f64;
+        if n == 1 {
+            return MetricSummary {
+                mean,
+                sd: 0.0,
+                ci_low: mean,
+                ci_high: mean,
+            };
+        }
+
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        let sd = variance.sqrt();
+        let t = Self::T_95.get(n - 2).copied().unwrap_or(Self::Z_95);
+        let half_width = t * sd / (n as f64).sqrt();
+        MetricSummary {
+            mean,
+            sd,
+            ci_low: mean - half_width,
+            ci_high: mean + half_width,
+        }
+    }
+}
+
+impl Display for MetricSummary {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.2} ± {:.2} (95% CI {:.2} to {:.2})",
+            self.mean, self.sd, self.ci_low, self.ci_high
+        )
+    }
+}
@@ -1,7 +1,59 @@
+mod cache_stats;
+mod checkpoint;
+mod checkpoint_schedule;
+mod checkpoint_state;
 mod edit_type;
 mod editor;
+mod fitness_metric;
+mod generation_limits;
+mod generation_summary;
+mod limit;
+mod limit_counts;
+mod limit_policy;
+mod lru_cache;
+mod metric_summary;
+mod observer;
+mod parallel_config;
+mod replicate_summary;
+mod resource_limits;
+mod rewrite_cache;
+mod rules;
 mod search_algos;
+mod search_result;
+mod simulation;
+mod simulation_builder;
+mod standard_rules;
+mod stop_condition;
+mod stop_event;
+mod strand_format;
+mod strand_output;
 
+pub use cache_stats::CacheStats;
+pub use checkpoint::Checkpoint;
+pub use checkpoint_schedule::CheckpointSchedule;
+pub use checkpoint_state::CheckpointState;
 pub use edit_type::EditType;
 pub use editor::Editor;
+pub use fitness_metric::FitnessMetric;
+pub use generation_limits::GenerationLimits;
+pub use generation_summary::GenerationSummary;
+pub use limit::Limit;
+pub use limit_counts::LimitCounts;
+pub use limit_policy::LimitPolicy;
+pub use lru_cache::LruCache;
+pub use metric_summary::MetricSummary;
+pub use observer::Observer;
+pub use parallel_config::ParallelConfig;
+pub use replicate_summary::ReplicateSummary;
+pub use resource_limits::ResourceLimits;
+pub use rewrite_cache::RewriteCache;
+pub use rules::Rules;
 pub use search_algos::SearchAlgos;
+pub use search_result::SearchResult;
+pub use simulation::Simulation;
+pub use simulation_builder::SimulationBuilder;
+pub use standard_rules::StandardRules;
+pub use stop_condition::StopCondition;
+pub use stop_event::StopEvent;
+pub use strand_format::StrandFormat;
+pub use strand_output::StrandOutput;
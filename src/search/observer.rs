@@ -0,0 +1,59 @@
+use crate::typogenetics::{Enzyme, Strand};
+
+/// Receives events from a running search. Iterations are counted from 1, and every
+/// method does nothing by default.
+pub trait Observer {
+    /// An enzyme coded by the catalyst rewrote the reactant into the products.
+    fn on_rewrite(
+        &mut self,
+        _iteration: usize,
+        _catalyst: &Strand,
+        _enzyme: &Enzyme,
+        _reactant: &Strand,
+        _products: &[Strand],
+    ) {
+    }
+
+    /// A strand was added to the soup for the first time.
+    fn on_new_strand(&mut self, _iteration: usize, _strand: &Strand) {}
+}
+
+impl Observer for () {}
+
+impl<T: Observer + ?Sized> Observer for &mut T {
+    fn on_rewrite(
+        &mut self,
+        iteration: usize,
+        catalyst: &Strand,
+        enzyme: &Enzyme,
+        reactant: &Strand,
+        products: &[Strand],
+    ) {
+        (**self).on_rewrite(iteration, catalyst, enzyme, reactant, products);
+    }
+
+    fn on_new_strand(&mut self, iteration: usize, strand: &Strand) {
+        (**self).on_new_strand(iteration, strand);
+    }
+}
+
+impl Observer for Vec<Box<dyn Observer + '_>> {
+    fn on_rewrite(
+        &mut self,
+        iteration: usize,
+        catalyst: &Strand,
+        enzyme: &Enzyme,
+        reactant: &Strand,
+        products: &[Strand],
+    ) {
+        for observer in self.iter_mut() {
+            observer.on_rewrite(iteration, catalyst, enzyme, reactant, products);
+        }
+    }
+
+    fn on_new_strand(&mut self, iteration: usize, strand: &Strand) {
+        for observer in self.iter_mut() {
+            observer.on_new_strand(iteration, strand);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// How `SearchAlgos::parallel` spreads a simulation over worker threads.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParallelConfig {
+    pub n_threads: usize,
+    pub seed: u64,
+    /// Make results depend only on the seed and not on the number of threads or how
+    /// they are scheduled, at some cost in throughput.
+    pub deterministic: bool,
+    /// Capacity of the `RewriteCache` of each worker.
+    pub cache_capacity: usize,
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::search::{MetricSummary, SearchResult};
+use crate::typogenetics::Strand;
+
+/// Summary metrics and discovered sets across replicate runs of a simulation.
+#[derive(Debug, Clone)]
+pub struct ReplicateSummary {
+    pub n_replicates: usize,
+    /// Replicates ended early by a stop condition or a limit.
+    pub n_stopped: usize,
+    pub metrics: Vec<(&'static str, MetricSummary)>,
+    /// Every strand discovered by any replicate with the number of replicates that
+    /// found it, in the order strands were first discovered.
+    pub strand_counts: Vec<(Strand, usize)>,
+}
+
+impl ReplicateSummary {
+    pub fn from_results(results: &[SearchResult]) -> ReplicateSummary {
+        let metric = |f: fn(&SearchResult) -> f64| {
+            let values: Vec<f64> = results.iter().map(f).collect();
+            MetricSummary::from_values(&values)
+        };
+        let metrics = vec![
+            ("unique strands", metric(|r| r.strands.len() as f64)),
+            ("iterations", metric(|r| r.n_iterations as f64)),
+            ("rewrites", metric(|r| r.n_rewrites as f64)),
+            ("rewrites/sec", metric(|r| r.get_rewrites_per_second())),
+            ("mean strand length", metric(Self::get_mean_strand_len)),
+            (
+                "max strand length",
+                metric(|r| r.strands.iter().map(|s| s.len()).max().unwrap_or(0) as f64),
+            ),
+        ];
+
+        let mut strand_counts: Vec<(Strand, usize)> = Vec::new();
+        let mut indices: HashMap<&Strand, usize> = HashMap::new();
+        for strand in results.iter().flat_map(|r| &r.strands) {
+            match indices.get(strand) {
+                Some(&i) => strand_counts[i].1 += 1,
+                None => {
+                    indices.insert(strand, strand_counts.len());
+                    strand_counts.push((strand.clone(), 1));
+                }
+            }
+        }
+
+        ReplicateSummary {
+            n_replicates: results.len(),
+            n_stopped: results
+                .iter()
+                .filter(|r| r.stop.is_some() || r.limits.stopped_by.is_some())
+                .count(),
+            metrics,
+            strand_counts,
+        }
+    }
+
+    /// Strands discovered by at least one replicate.
+    pub fn get_union(&self) -> Vec<&Strand> {
+        self.strand_counts.iter().map(|(s, _)| s).collect()
+    }
+
+    /// Strands discovered by every replicate.
+    pub fn get_intersection(&self) -> Vec<&Strand> {
+        self.strand_counts
+            .iter()
+            .filter(|(_, count)| *count == self.n_replicates)
+            .map(|(s, _)| s)
+            .collect()
+    }
+
+    fn get_mean_strand_len(result: &SearchResult) -> f64 {
+        let total: usize = result.strands.iter().map(|s| s.len()).sum();
+        total as f64 / result.strands.len().max(1) as f64
+    }
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use crate::search::LimitPolicy;
+
+/// Bounds on a simulation. Limits on the soup apply `policy` to products that
+/// would break them, while the time and rewrite limits always end the run.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ResourceLimits {
+    pub max_strand_len: Option<usize>,
+    pub max_population: Option<usize>,
+    pub max_total_bases: Option<usize>,
+    pub time_limit: Option<Duration>,
+    pub max_rewrites: Option<usize>,
+    pub policy: LimitPolicy,
+}
+
+impl ResourceLimits {
+    pub fn unlimited() -> ResourceLimits {
+        ResourceLimits {
+            max_strand_len: None,
+            max_population: None,
+            max_total_bases: None,
+            time_limit: None,
+            max_rewrites: None,
+            policy: LimitPolicy::Drop,
+        }
+    }
+}
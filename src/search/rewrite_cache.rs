@@ -0,0 +1,96 @@
+use crate::search::{CacheStats, LruCache, Rules};
+use crate::typogenetics::{Enzyme, Rewriter, Strand, Translator};
+
+/// Memoises `Translator::translate` per strand and `Rewriter::rewrite` per enzyme and
+/// strand, each in an LRU cache of the same capacity. A capacity of zero bypasses the
+/// caches entirely, without building keys or counting misses.
+pub struct RewriteCache {
+    translations: LruCache<Strand, Vec<Enzyme>>,
+    rewrites: LruCache<(Enzyme, Strand), Vec<Strand>>,
+    stats: CacheStats,
+}
+
+impl RewriteCache {
+    pub fn new(capacity: usize) -> RewriteCache {
+        RewriteCache {
+            translations: LruCache::new(capacity),
+            rewrites: LruCache::new(capacity),
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn translate(&mut self, strand: &Strand) -> Vec<Enzyme> {
+        if self.is_disabled() {
+            return Translator::translate(strand);
+        }
+        if let Some(enzymes) = self.translations.get(strand) {
+            self.stats.translation_hits += 1;
+            return enzymes.clone();
+        }
+        self.stats.translation_misses += 1;
+        let enzymes = Translator::translate(strand);
+        self.translations.insert(strand.clone(), enzymes.clone());
+        enzymes
+    }
+
+    pub fn rewrite(&mut self, enzyme: &Enzyme, strand: &Strand) -> Vec<Strand> {
+        if self.is_disabled() {
+            return Rewriter::rewrite(enzyme, strand);
+        }
+        let key = (enzyme.clone(), strand.clone());
+        if let Some(strands) = self.rewrites.get(&key) {
+            self.stats.rewrite_hits += 1;
+            return strands.clone();
+        }
+        self.stats.rewrite_misses += 1;
+        let strands = Rewriter::rewrite(enzyme, strand);
+        self.rewrites.insert(key, strands.clone());
+        strands
+    }
+
+    pub fn get_stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.translations.get_capacity() == 0
+    }
+}
+
+impl Rules for RewriteCache {
+    fn translate(&mut self, strand: &Strand) -> Vec<Enzyme> {
+        RewriteCache::translate(self, strand)
+    }
+
+    fn rewrite(&mut self, enzyme: &Enzyme, strand: &Strand) -> Vec<Strand> {
+        RewriteCache::rewrite(self, enzyme, strand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_capacity_bypasses_cache() {
+        let mut cache = RewriteCache::new(0);
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let enzymes = cache.translate(&strand);
+        cache.rewrite(&enzymes[0], &strand);
+
+        assert_eq!(enzymes, Translator::translate(&strand));
+        assert_eq!(cache.get_stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn repeated_rewrite_hits() {
+        let mut cache = RewriteCache::new(10);
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let enzyme = Enzyme::from_string("cut-cop");
+        let products = cache.rewrite(&enzyme, &strand);
+
+        assert_eq!(cache.rewrite(&enzyme, &strand), products);
+        assert_eq!(cache.get_stats().rewrite_hits, 1);
+        assert_eq!(cache.get_stats().rewrite_misses, 1);
+    }
+}
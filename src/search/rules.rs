@@ -0,0 +1,9 @@
+use crate::typogenetics::{Enzyme, Strand};
+
+/// How strands code for enzymes and how enzymes rewrite strands during a search.
+/// Methods take `&mut self` so that rules can keep state, such as a cache.
+pub trait Rules {
+    fn translate(&mut self, strand: &Strand) -> Vec<Enzyme>;
+
+    fn rewrite(&mut self, enzyme: &Enzyme, strand: &Strand) -> Vec<Strand>;
+}
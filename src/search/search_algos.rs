@@ -1,52 +1,1607 @@
-use crate::typogenetics::{Rewriter, Strand, Translator};
+use crate::analysis::Reaction;
+use crate::fasta::{FastaRecord, FastaWriter};
+use crate::search::{
+    CacheStats, Checkpoint, CheckpointSchedule, CheckpointState, GenerationLimits,
+    GenerationSummary, Limit, LimitCounts, LimitPolicy, Observer, ParallelConfig, ReplicateSummary,
+    ResourceLimits, RewriteCache, Rules, SearchResult, StopCondition, StopEvent, StrandFormat,
+    StrandOutput,
+};
+use crate::typogenetics::{Enzyme, Strand};
 use rand::Rng;
-use rand_chacha::ChaCha8Rng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::thread;
+use std::time::Instant;
 
 pub struct SearchAlgos;
 
+/// The strands discovered so far, in the order they were found.
+struct Soup {
+    strands: Vec<Strand>,
+    known_set: HashSet<Strand>,
+    total_bases: usize,
+}
+
+impl Soup {
+    fn new(init_strand: &Strand) -> Soup {
+        Soup::from_strands(vec![init_strand.clone()])
+    }
+
+    fn from_strands(strands: Vec<Strand>) -> Soup {
+        let known_set = strands.iter().cloned().collect();
+        let total_bases = strands.iter().map(|s| s.len()).sum();
+        Soup {
+            strands,
+            known_set,
+            total_bases,
+        }
+    }
+
+    fn insert(&mut self, strand: Strand) {
+        if self.known_set.insert(strand.clone()) {
+            self.total_bases += strand.len();
+            self.strands.push(strand);
+        }
+    }
+
+    /// Bases of the initial strand, which is never evicted.
+    fn get_pinned_bases(&self) -> usize {
+        self.strands.first().map_or(0, |strand| strand.len())
+    }
+
+    /// Remove the oldest strand other than the initial one, returning false if there is
+    /// none left to remove.
+    fn evict_oldest(&mut self) -> bool {
+        if self.strands.len() < 2 {
+            return false;
+        }
+        let strand = self.strands.remove(1);
+        self.total_bases -= strand.len();
+        self.known_set.remove(&strand);
+        true
+    }
+}
+
 impl SearchAlgos {
+    /// Iterations simulated against the same snapshot of the soup in deterministic mode.
+    const ROUND_SIZE: usize = 1024;
+
     pub fn random(
         init_strand: &Strand,
         n_iterations: i32,
         rng: &mut ChaCha8Rng,
-        print_strands: bool,
+        cache: &mut RewriteCache,
+        print_strands: Option<&StrandOutput>,
     ) {
-        let mut strands = vec![init_strand.clone()];
-        let mut known_set = HashSet::new();
-        known_set.insert(format!("{:?}", init_strand));
+        let checkpoint = Checkpoint::start(
+            std::slice::from_ref(init_strand),
+            CheckpointState::Random {
+                rng: Box::new(rng.clone()),
+                last_new_iteration: 0,
+            },
+        );
+        let n_iterations = n_iterations.max(0) as usize;
+        let limits = ResourceLimits::unlimited();
+        Self::random_from(
+            checkpoint,
+            n_iterations,
+            cache,
+            None,
+            &limits,
+            &[],
+            print_strands,
+        )
+        .expect("Searches without checkpoints cannot fail");
+    }
+
+    /// Like `random`, but continuing from a checkpoint, writing checkpoints on a schedule,
+    /// within resource limits and until a stop condition is met. Fails if a checkpoint
+    /// cannot be written.
+    pub fn random_from(
+        checkpoint: Checkpoint,
+        n_iterations: usize,
+        cache: &mut RewriteCache,
+        schedule: Option<&CheckpointSchedule>,
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        print_strands: Option<&StrandOutput>,
+    ) -> Result<(), String> {
+        let result = Self::resume_random(
+            checkpoint,
+            n_iterations,
+            cache,
+            schedule,
+            limits,
+            stop_conditions,
+            &mut (),
+        )?;
+        let mut summary = format!(
+            "Discovered {} unique strands while simulating for {} iterations",
+            result.strands.len(),
+            result.n_iterations
+        );
+        if !result.limits.is_empty() {
+            summary = format!("{}\n{}", summary, result.limits);
+        }
+        if let Some(stop) = &result.stop {
+            summary = format!("{}\n{}", summary, stop);
+        }
+        Self::print_results(&result.strands, &summary, print_strands);
+        Ok(())
+    }
+
+    /// The search behind `random`, where each iteration rewrites a random strand with a
+    /// random enzyme of a random strand.
+    pub fn run_random(
+        init_strand: &Strand,
+        n_iterations: usize,
+        rng: ChaCha8Rng,
+        cache: &mut RewriteCache,
+    ) -> SearchResult {
+        let checkpoint = Checkpoint::start(
+            std::slice::from_ref(init_strand),
+            CheckpointState::Random {
+                rng: Box::new(rng),
+                last_new_iteration: 0,
+            },
+        );
+        Self::resume_random(
+            checkpoint,
+            n_iterations,
+            cache,
+            None,
+            &ResourceLimits::unlimited(),
+            &[],
+            &mut (),
+        )
+        .expect("Searches without checkpoints cannot fail")
+    }
+
+    /// Continue `run_random` from a checkpoint until `n_iterations` iterations have been
+    /// run in total, or a limit or stop condition stops the run, sending events of the run
+    /// to the observer. The result is the same as that of a run
+    /// that was never interrupted, except that the time limit and limit counts only
+    /// cover the resumed part.
+    pub fn resume_random(
+        checkpoint: Checkpoint,
+        n_iterations: usize,
+        rules: &mut dyn Rules,
+        schedule: Option<&CheckpointSchedule>,
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        observer: &mut dyn Observer,
+    ) -> Result<SearchResult, String> {
+        let CheckpointState::Random {
+            mut rng,
+            mut last_new_iteration,
+        } = checkpoint.state
+        else {
+            return Err(String::from("Checkpoint is not from a random simulation"));
+        };
+        let start = Instant::now();
+        let mut soup = Soup::from_strands(checkpoint.strands);
+        let mut n_rewrites = checkpoint.n_rewrites;
+        let mut counts = LimitCounts::new(limits.policy);
+        let mut stop_event =
+            Self::find_initial_stop(&soup.strands, stop_conditions, checkpoint.iteration);
+        let mut n_done = if stop_event.is_some() {
+            checkpoint.iteration
+        } else {
+            n_iterations.max(checkpoint.iteration)
+        };
+        let save = |soup: &Soup,
+                    iteration,
+                    n_rewrites,
+                    rng: &ChaCha8Rng,
+                    last_new_iteration,
+                    path: &str| {
+            Checkpoint {
+                strands: soup.strands.clone(),
+                iteration,
+                n_rewrites,
+                state: CheckpointState::Random {
+                    rng: Box::new(rng.clone()),
+                    last_new_iteration,
+                },
+            }
+            .save(path)
+        };
+
+        for iteration in checkpoint.iteration..n_done {
+            if let Some(schedule) = schedule {
+                if iteration > checkpoint.iteration
+                    && iteration.is_multiple_of(schedule.every.max(1))
+                {
+                    save(
+                        &soup,
+                        iteration,
+                        n_rewrites,
+                        &rng,
+                        last_new_iteration,
+                        &schedule.path,
+                    )?;
+                    log::info!("Wrote checkpoint at iteration {}", iteration);
+                }
+            }
+
+            if let Some(limit) = Self::find_run_limit(limits, start, n_rewrites) {
+                counts.fire(limit);
+                counts.stop(limit, iteration);
+                n_done = iteration;
+                break;
+            }
+            stop_event = Self::find_stagnation(stop_conditions, iteration, last_new_iteration);
+            if stop_event.is_some() {
+                n_done = iteration;
+                break;
+            }
 
-        for _ in 0..n_iterations {
-            let enzyme_strand = &strands[rng.gen_range(0..strands.len())];
-            let enzymes = Translator::translate(enzyme_strand);
+            let enzyme_strand = &soup.strands[rng.gen_range(0..soup.strands.len())];
+            let enzymes = rules.translate(enzyme_strand);
             if enzymes.is_empty() {
                 continue;
             }
             let enzyme_idx = rng.gen_range(0..enzymes.len());
             let enzyme = &enzymes[enzyme_idx];
-            let rewrite_strand = &strands[rng.gen_range(0..strands.len())];
-            let new_strands = Rewriter::rewrite(enzyme, rewrite_strand);
+            let rewrite_strand = &soup.strands[rng.gen_range(0..soup.strands.len())];
+            let new_strands = rules.rewrite(enzyme, rewrite_strand);
+            n_rewrites += 1;
+            observer.on_rewrite(
+                iteration + 1,
+                enzyme_strand,
+                enzyme,
+                rewrite_strand,
+                &new_strands,
+            );
+            // Only kept to report which reaction met a stop condition
+            let reaction = (!stop_conditions.is_empty()).then(|| Reaction {
+                catalyst: enzyme_strand.clone(),
+                enzyme: enzyme.clone(),
+                reactant: rewrite_strand.clone(),
+                products: new_strands.clone(),
+            });
+
+            let mut stop = None;
             for strand in new_strands {
-                if !known_set.contains(&format!("{:?}", strand)) {
-                    strands.push(strand.clone());
-                    known_set.insert(format!("{:?}", strand));
+                match Self::admit(&mut soup, strand.clone(), limits, &mut counts) {
+                    Err(limit) => stop = Some(limit),
+                    Ok(false) => {}
+                    Ok(true) => {
+                        last_new_iteration = iteration + 1;
+                        observer.on_new_strand(iteration + 1, &strand);
+                        if stop_event.is_none() {
+                            stop_event = Self::find_strand_stop(
+                                stop_conditions,
+                                iteration + 1,
+                                &strand,
+                                reaction.as_ref(),
+                            );
+                        }
+                    }
+                }
+            }
+            if let Some(limit) = stop {
+                counts.stop(limit, iteration + 1);
+            }
+            if stop.is_some() || stop_event.is_some() {
+                n_done = iteration + 1;
+                break;
+            }
+        }
+
+        if let Some(schedule) = schedule {
+            save(
+                &soup,
+                n_done,
+                n_rewrites,
+                &rng,
+                last_new_iteration,
+                &schedule.path,
+            )?;
+        }
+        Ok(SearchResult {
+            strands: soup.strands,
+            n_iterations: n_done,
+            n_rewrites,
+            elapsed: start.elapsed(),
+            limits: counts,
+            stop: stop_event,
+        })
+    }
+
+    /// The first condition met by one of the strands a search starts from, which stops
+    /// the search before it runs a single iteration.
+    fn find_initial_stop(
+        strands: &[Strand],
+        stop_conditions: &[StopCondition],
+        iteration: usize,
+    ) -> Option<StopEvent> {
+        strands
+            .iter()
+            .find_map(|strand| Self::find_strand_stop(stop_conditions, iteration, strand, None))
+    }
+
+    /// The first condition met by a strand, with the reaction that produced it.
+    fn find_strand_stop(
+        stop_conditions: &[StopCondition],
+        iteration: usize,
+        strand: &Strand,
+        reaction: Option<&Reaction>,
+    ) -> Option<StopEvent> {
+        let condition = stop_conditions.iter().find(|c| c.is_met_by(strand))?;
+        Some(StopEvent {
+            condition: condition.clone(),
+            iteration,
+            strand: Some(strand.clone()),
+            reaction: reaction.cloned(),
+        })
+    }
+
+    /// The first stagnation condition met at the start of an iteration, given the
+    /// iteration that discovered the latest new strand.
+    fn find_stagnation(
+        stop_conditions: &[StopCondition],
+        iteration: usize,
+        last_new_iteration: usize,
+    ) -> Option<StopEvent> {
+        let condition = stop_conditions.iter().find(|c| match c {
+            StopCondition::Stagnation(k) => iteration.saturating_sub(last_new_iteration) >= *k,
+            _ => false,
+        })?;
+        Some(StopEvent {
+            condition: condition.clone(),
+            iteration,
+            strand: None,
+            reaction: None,
+        })
+    }
+
+    /// The time or rewrite limit that ends a run started at `start`, if either is reached.
+    fn find_run_limit(limits: &ResourceLimits, start: Instant, n_rewrites: usize) -> Option<Limit> {
+        if limits.time_limit.is_some_and(|t| start.elapsed() >= t) {
+            Some(Limit::WallClock)
+        } else if limits.max_rewrites.is_some_and(|max| n_rewrites >= max) {
+            Some(Limit::Rewrites)
+        } else {
+            None
+        }
+    }
+
+    /// Add a product to the soup unless it breaks a limit on the soup, in which case the
+    /// policy decides what happens. A product that would break the limit even if every
+    /// strand but the initial one were evicted is dropped without evicting anything.
+    /// Returns whether the product is a new strand in the soup, or the limit if the run
+    /// should stop.
+    fn admit(
+        soup: &mut Soup,
+        strand: Strand,
+        limits: &ResourceLimits,
+        counts: &mut LimitCounts,
+    ) -> Result<bool, Limit> {
+        if soup.known_set.contains(&strand) {
+            return Ok(false);
+        }
+        if limits.max_strand_len.is_some_and(|max| strand.len() > max) {
+            counts.fire(Limit::StrandLength);
+            return match limits.policy {
+                LimitPolicy::Stop => Err(Limit::StrandLength),
+                LimitPolicy::Drop | LimitPolicy::Evict => Ok(false),
+            };
+        }
+
+        for limit in [Limit::Population, Limit::TotalBases] {
+            let is_over = |n_strands: usize, total_bases: usize| match limit {
+                Limit::Population => limits.max_population.is_some_and(|max| n_strands >= max),
+                _ => limits
+                    .max_total_bases
+                    .is_some_and(|max| total_bases + strand.len() > max),
+            };
+            if !is_over(soup.strands.len(), soup.total_bases) {
+                continue;
+            }
+            counts.fire(limit);
+            match limits.policy {
+                LimitPolicy::Drop => return Ok(false),
+                LimitPolicy::Stop => return Err(limit),
+                LimitPolicy::Evict => {
+                    if is_over(1, soup.get_pinned_bases()) {
+                        return Ok(false);
+                    }
+                    while is_over(soup.strands.len(), soup.total_bases) {
+                        if !soup.evict_oldest() {
+                            return Ok(false);
+                        }
+                        counts.evict();
+                    }
+                }
+            }
+        }
+
+        soup.insert(strand);
+        Ok(true)
+    }
+
+    /// Apply every enzyme of every strand in the set to every strand in the set, adding
+    /// new products to the set, until a generation finds nothing new or a limit is hit.
+    /// Strands are visited in the order they were discovered, so the result needs no seed.
+    pub fn generational(
+        init_strand: &Strand,
+        limits: GenerationLimits,
+        cache: &mut RewriteCache,
+        print_strands: Option<&StrandOutput>,
+    ) {
+        let checkpoint = Checkpoint::start(
+            std::slice::from_ref(init_strand),
+            CheckpointState::Generational { n_done: 0 },
+        );
+        Self::generational_from(checkpoint, limits, cache, None, &[], print_strands)
+            .expect("Searches without checkpoints cannot fail");
+    }
+
+    /// Like `generational`, but continuing from a checkpoint, writing checkpoints on a
+    /// schedule and until a stop condition is met. Fails if a checkpoint cannot be written.
+    pub fn generational_from(
+        checkpoint: Checkpoint,
+        limits: GenerationLimits,
+        cache: &mut RewriteCache,
+        schedule: Option<&CheckpointSchedule>,
+        stop_conditions: &[StopCondition],
+        print_strands: Option<&StrandOutput>,
+    ) -> Result<(), String> {
+        let result = Self::resume_generations(
+            checkpoint,
+            limits,
+            cache,
+            schedule,
+            stop_conditions,
+            |summary| {
+                if print_strands.is_some_and(|output| output.format == StrandFormat::Fasta) {
+                    eprintln!("{}", summary);
+                } else {
+                    println!("{}", summary);
+                }
+            },
+            |_, _, _, _| {},
+        )?;
+
+        let mut summary = format!(
+            "Discovered {} unique strands while simulating generations",
+            result.strands.len()
+        );
+        if !result.limits.is_empty() {
+            summary = format!("{}\n{}", summary, result.limits);
+        }
+        if let Some(stop) = &result.stop {
+            summary = format!("{}\n{}", summary, stop);
+        }
+        Self::print_results(&result.strands, &summary, print_strands);
+        Ok(())
+    }
+
+    /// The generational search behind `generational`, starting from a set of strands.
+    /// `on_rewrite` sees the strand coding the enzyme, the enzyme, the rewritten strand
+    /// and the products of every rewrite. Returns every strand in the final set.
+    pub fn run_generations(
+        init_strands: &[Strand],
+        limits: GenerationLimits,
+        cache: &mut RewriteCache,
+        on_generation: impl FnMut(&GenerationSummary),
+        on_rewrite: impl FnMut(&Strand, &Enzyme, &Strand, &[Strand]),
+    ) -> Vec<Strand> {
+        let checkpoint =
+            Checkpoint::start(init_strands, CheckpointState::Generational { n_done: 0 });
+        Self::resume_generations(
+            checkpoint,
+            limits,
+            cache,
+            None,
+            &[],
+            on_generation,
+            on_rewrite,
+        )
+        .expect("Searches without checkpoints cannot fail")
+        .strands
+    }
+
+    /// Continue `run_generations` from a checkpoint, writing a checkpoint after every
+    /// `schedule.every` generations and after the last. Stops after the generation in
+    /// which a strand first meets one of the stop conditions, and returns that event too,
+    /// with the generation as its iteration. Stagnation never stops a generational search,
+    /// which ends by itself once a generation finds nothing new. A limit that stops the
+    /// run does so mid-generation, and resuming from the last checkpoint repeats that
+    /// generation.
+    pub fn resume_generations(
+        checkpoint: Checkpoint,
+        limits: GenerationLimits,
+        cache: &mut RewriteCache,
+        schedule: Option<&CheckpointSchedule>,
+        stop_conditions: &[StopCondition],
+        mut on_generation: impl FnMut(&GenerationSummary),
+        mut on_rewrite: impl FnMut(&Strand, &Enzyme, &Strand, &[Strand]),
+    ) -> Result<SearchResult, String> {
+        let CheckpointState::Generational { mut n_done } = checkpoint.state else {
+            return Err(String::from(
+                "Checkpoint is not from a generational simulation",
+            ));
+        };
+        let start = Instant::now();
+        let resources = &limits.resources;
+        let mut soup = Soup::from_strands(checkpoint.strands);
+        let mut n_rewrites = checkpoint.n_rewrites;
+        let mut counts = LimitCounts::new(resources.policy);
+        let mut enzymes: Vec<Vec<Enzyme>> = Vec::new();
+        let mut generation = checkpoint.iteration;
+        let mut stop_event = Self::find_initial_stop(&soup.strands, stop_conditions, generation);
+        let save = |strands: &[Strand], generation, n_rewrites, n_done, path: &str| {
+            Checkpoint {
+                strands: strands.to_vec(),
+                iteration: generation,
+                n_rewrites,
+                state: CheckpointState::Generational { n_done },
+            }
+            .save(path)
+        };
+
+        while generation < limits.max_generations && stop_event.is_none() {
+            generation += 1;
+            // Evictions shift the strands of the set, so the generation pairs up a snapshot
+            let current = soup.strands.clone();
+            for strand in &current[enzymes.len()..] {
+                enzymes.push(cache.translate(strand));
+            }
+
+            let mut summary = GenerationSummary {
+                generation,
+                n_rewrites: 0,
+                n_new: 0,
+                n_strands: 0,
+                n_too_long: 0,
+                n_dropped: 0,
+                n_evicted: 0,
+            };
+            let n_too_long = counts.get_count(Limit::StrandLength);
+            let n_evicted = counts.get_n_evicted();
+            let mut n_rejected = 0;
+            // Pairs of strands below n_done were already rewritten in an earlier generation
+            'pairs: for (i, strand_enzymes) in enzymes.iter().enumerate() {
+                for enzyme in strand_enzymes {
+                    for j in 0..current.len() {
+                        if i < n_done && j < n_done {
+                            continue;
+                        }
+                        let n_total = n_rewrites + summary.n_rewrites;
+                        if let Some(limit) = Self::find_run_limit(resources, start, n_total) {
+                            counts.fire(limit);
+                            counts.stop(limit, generation);
+                            break 'pairs;
+                        }
+                        summary.n_rewrites += 1;
+                        let new_strands = cache.rewrite(enzyme, &current[j]);
+                        on_rewrite(&current[i], enzyme, &current[j], &new_strands);
+                        // Only kept to report which reaction met a stop condition
+                        let reaction = (!stop_conditions.is_empty()).then(|| Reaction {
+                            catalyst: current[i].clone(),
+                            enzyme: enzyme.clone(),
+                            reactant: current[j].clone(),
+                            products: new_strands.clone(),
+                        });
+
+                        let mut stop = None;
+                        for strand in new_strands {
+                            if soup.known_set.contains(&strand) {
+                                continue;
+                            }
+                            match Self::admit(&mut soup, strand.clone(), resources, &mut counts) {
+                                Err(limit) => {
+                                    n_rejected += 1;
+                                    stop = Some(limit);
+                                }
+                                Ok(false) => n_rejected += 1,
+                                Ok(true) => {
+                                    if stop_event.is_none() {
+                                        stop_event = Self::find_strand_stop(
+                                            stop_conditions,
+                                            generation,
+                                            &strand,
+                                            reaction.as_ref(),
+                                        );
+                                    }
+                                    summary.n_new += 1;
+                                }
+                            }
+                        }
+                        if let Some(limit) = stop {
+                            counts.stop(limit, generation);
+                            break 'pairs;
+                        }
+                    }
+                }
+            }
+            n_rewrites += summary.n_rewrites;
+            summary.n_strands = soup.strands.len();
+            summary.n_too_long = counts.get_count(Limit::StrandLength) - n_too_long;
+            summary.n_dropped = n_rejected - summary.n_too_long;
+            summary.n_evicted = counts.get_n_evicted() - n_evicted;
+
+            // Strands are evicted oldest first after the initial one, so the strands whose
+            // pairs are all done stay at the front of the set
+            let is_stopped = counts.stopped_by.is_some();
+            let n_paired = if is_stopped { n_done } else { current.len() };
+            let n_gone = summary.n_evicted.min(current.len() - 1);
+            enzymes.drain(1..1 + n_gone);
+            n_done = n_paired - n_gone.min(n_paired.saturating_sub(1));
+
+            on_generation(&summary);
+            if is_stopped {
+                break;
+            }
+            if summary.n_new == 0 || stop_event.is_some() {
+                break;
+            }
+            if let Some(schedule) = schedule {
+                if generation.is_multiple_of(schedule.every.max(1)) {
+                    save(
+                        &soup.strands,
+                        generation,
+                        n_rewrites,
+                        n_done,
+                        &schedule.path,
+                    )?;
+                    log::info!("Wrote checkpoint at generation {}", generation);
                 }
             }
         }
 
-        if print_strands {
-            println!("Unique strands:");
-            let mut sorted_strands: Vec<String> = known_set.clone().into_iter().collect();
-            sorted_strands.sort();
-            for strand_str in sorted_strands {
-                println!("- {}", strand_str);
+        if let Some(schedule) = schedule {
+            // A generation stopped by a limit is left for the resumed run to repeat
+            let n_complete = match counts.stopped_by {
+                Some(_) => generation - 1,
+                None => generation,
+            };
+            save(
+                &soup.strands,
+                n_complete,
+                n_rewrites,
+                n_done,
+                &schedule.path,
+            )?;
+        }
+        Ok(SearchResult {
+            strands: soup.strands,
+            n_iterations: generation,
+            n_rewrites,
+            elapsed: start.elapsed(),
+            limits: counts,
+            stop: stop_event,
+        })
+    }
+
+    /// Like `random`, but with worker threads drawing enzyme and target pairs from a shared
+    /// soup. Each worker samples from its own ChaCha stream of the seed. In deterministic
+    /// mode the soup is instead frozen for rounds of `ROUND_SIZE` iterations, and each
+    /// iteration samples from a stream numbered by the iteration, so the result does not
+    /// depend on the number of threads. Returns the combined stats of the workers' caches.
+    pub fn parallel(
+        init_strand: &Strand,
+        n_iterations: usize,
+        config: ParallelConfig,
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        print_strands: Option<&StrandOutput>,
+    ) -> CacheStats {
+        let (result, stats) =
+            Self::run_parallel(init_strand, n_iterations, config, limits, stop_conditions);
+        let mut summary = format!(
+            "Discovered {} unique strands while simulating for {} iterations on {} threads\n\
+             Performed {} rewrites in {:.2}s, {:.0} rewrites/sec",
+            result.strands.len(),
+            result.n_iterations,
+            config.n_threads.max(1),
+            result.n_rewrites,
+            result.elapsed.as_secs_f64(),
+            result.get_rewrites_per_second()
+        );
+        if !result.limits.is_empty() {
+            summary = format!("{}\n{}", summary, result.limits);
+        }
+        if let Some(stop) = &result.stop {
+            summary = format!("{}\n{}", summary, stop);
+        }
+        Self::print_results(&result.strands, &summary, print_strands);
+        stats
+    }
+
+    /// The search behind `parallel`. Without deterministic mode, workers stop soon after
+    /// one of them meets a stop condition or limit, so iterations may have run past the
+    /// reported one, and rewrites past the rewrite limit.
+    pub fn run_parallel(
+        init_strand: &Strand,
+        n_iterations: usize,
+        config: ParallelConfig,
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+    ) -> (SearchResult, CacheStats) {
+        let n_threads = config.n_threads.max(1);
+        let mut caches: Vec<RewriteCache> = (0..n_threads)
+            .map(|_| RewriteCache::new(config.cache_capacity))
+            .collect();
+        let start = Instant::now();
+
+        let initial_stop =
+            Self::find_initial_stop(std::slice::from_ref(init_strand), stop_conditions, 0);
+        let result = if initial_stop.is_some() {
+            SearchResult {
+                strands: vec![init_strand.clone()],
+                n_iterations: 0,
+                n_rewrites: 0,
+                elapsed: start.elapsed(),
+                limits: LimitCounts::new(limits.policy),
+                stop: initial_stop,
             }
+        } else if config.deterministic {
+            Self::run_rounds(
+                init_strand,
+                n_iterations,
+                config.seed,
+                &mut caches,
+                limits,
+                stop_conditions,
+                start,
+            )
+        } else {
+            Self::run_workers(
+                init_strand,
+                n_iterations,
+                config.seed,
+                &mut caches,
+                limits,
+                stop_conditions,
+                start,
+            )
+        };
+
+        let mut stats = CacheStats::default();
+        for cache in &caches {
+            stats.add(&cache.get_stats());
+        }
+        (result, stats)
+    }
+
+    /// Run `random`, or `parallel` if given a config, once for each of `n_replicates`
+    /// consecutive seeds starting at `seed`, and report statistics across the runs. Each
+    /// run stops on its own when it hits a limit or meets a stop condition.
+    #[allow(clippy::too_many_arguments)]
+    pub fn replicates(
+        init_strand: &Strand,
+        n_iterations: usize,
+        n_replicates: usize,
+        seed: u64,
+        cache_capacity: usize,
+        parallel: Option<ParallelConfig>,
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        print_strands: Option<&StrandOutput>,
+    ) -> ReplicateSummary {
+        let results: Vec<SearchResult> = (seed..seed + n_replicates as u64)
+            .map(|seed| {
+                let result = match parallel {
+                    Some(config) => {
+                        let config = ParallelConfig { seed, ..config };
+                        Self::run_parallel(
+                            init_strand,
+                            n_iterations,
+                            config,
+                            limits,
+                            stop_conditions,
+                        )
+                        .0
+                    }
+                    None => {
+                        let checkpoint = Checkpoint::start(
+                            std::slice::from_ref(init_strand),
+                            CheckpointState::Random {
+                                rng: Box::new(ChaCha8Rng::seed_from_u64(seed)),
+                                last_new_iteration: 0,
+                            },
+                        );
+                        Self::resume_random(
+                            checkpoint,
+                            n_iterations,
+                            &mut RewriteCache::new(cache_capacity),
+                            None,
+                            limits,
+                            stop_conditions,
+                            &mut (),
+                        )
+                        .expect("Searches without checkpoints cannot fail")
+                    }
+                };
+                log::info!(
+                    "Seed {} discovered {} unique strands",
+                    seed,
+                    result.strands.len()
+                );
+                result
+            })
+            .collect();
+        let summary = ReplicateSummary::from_results(&results);
+
+        let mut lines = vec![format!(
+            "Ran {} replicates of {} iterations with seeds {} to {}",
+            n_replicates,
+            n_iterations,
+            seed,
+            seed + n_replicates.max(1) as u64 - 1
+        )];
+        for (name, metric) in &summary.metrics {
+            lines.push(format!("{}: {}", name, metric));
         }
+        if summary.n_stopped > 0 {
+            lines.push(format!(
+                "{} of {} replicates stopped early",
+                summary.n_stopped, n_replicates
+            ));
+        }
+        lines.push(format!(
+            "Union: {} strands, intersection: {} strands",
+            summary.get_union().len(),
+            summary.get_intersection().len()
+        ));
+        let text = lines.join("\n");
 
-        println!(
-            "Discovered {} unique strands while simulating for {} iterations",
-            known_set.len(),
-            n_iterations
+        let mut strand_counts: Vec<(String, usize)> = summary
+            .strand_counts
+            .iter()
+            .filter(|(s, _)| print_strands.is_some_and(|output| output.is_selected(s)))
+            .map(|(s, count)| (format!("{:?}", s), *count))
+            .collect();
+        strand_counts.sort();
+        match print_strands.map(|output| output.format) {
+            Some(StrandFormat::Plain) => {
+                println!("Strands with the number of replicates that found them:");
+                for (strand_str, count) in strand_counts {
+                    println!("- {} ({}/{})", strand_str, count, n_replicates);
+                }
+                println!("{}", text);
+            }
+            Some(StrandFormat::Fasta) => {
+                let records: Vec<FastaRecord> = strand_counts
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (strand_str, count))| FastaRecord {
+                        id: format!("strand_{}", i + 1),
+                        description: format!("found in {} of {} replicates", count, n_replicates),
+                        strand: Strand::from_string(strand_str),
+                    })
+                    .collect();
+                FastaWriter::write(&mut std::io::stdout().lock(), &records)
+                    .expect("Failed to write strands");
+                eprintln!("{}", text);
+            }
+            None => println!("{}", text),
+        }
+
+        summary
+    }
+
+    fn run_workers(
+        init_strand: &Strand,
+        n_iterations: usize,
+        seed: u64,
+        caches: &mut [RewriteCache],
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        start: Instant,
+    ) -> SearchResult {
+        let soup = RwLock::new(Soup::new(init_strand));
+        let counts = Mutex::new(LimitCounts::new(limits.policy));
+        let next_iteration = AtomicUsize::new(0);
+        let last_new_iteration = AtomicUsize::new(0);
+        let n_rewrites = AtomicUsize::new(0);
+        let stop_event = Mutex::new(None);
+        let stopped = AtomicBool::new(false);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = caches
+                .iter_mut()
+                .enumerate()
+                .map(|(worker, cache)| {
+                    let soup = &soup;
+                    let counts = &counts;
+                    let next_iteration = &next_iteration;
+                    let last_new_iteration = &last_new_iteration;
+                    let n_rewrites = &n_rewrites;
+                    let stopped = &stopped;
+                    // The first worker to meet a condition reports it
+                    let stop = |event: Option<StopEvent>| {
+                        let Some(event) = event else {
+                            return false;
+                        };
+                        stop_event
+                            .lock()
+                            .expect("Stop lock poisoned")
+                            .get_or_insert(event);
+                        stopped.store(true, Ordering::Relaxed);
+                        true
+                    };
+                    // The first worker to hit a limit that ends the run reports it
+                    let stop_at_limit = |counts: &mut LimitCounts, limit, iteration| {
+                        if counts.stopped_by.is_none() {
+                            counts.stop(limit, iteration);
+                        }
+                        stopped.store(true, Ordering::Relaxed);
+                    };
+                    scope.spawn(move || {
+                        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                        rng.set_stream(worker as u64);
+                        loop {
+                            let iteration = next_iteration.fetch_add(1, Ordering::Relaxed);
+                            if iteration >= n_iterations || stopped.load(Ordering::Relaxed) {
+                                break;
+                            }
+                            let n_done = n_rewrites.load(Ordering::Relaxed);
+                            if let Some(limit) = Self::find_run_limit(limits, start, n_done) {
+                                let mut counts = counts.lock().expect("Limit lock poisoned");
+                                counts.fire(limit);
+                                stop_at_limit(&mut counts, limit, iteration);
+                                break;
+                            }
+                            if stop(Self::find_stagnation(
+                                stop_conditions,
+                                iteration,
+                                last_new_iteration.load(Ordering::Relaxed),
+                            )) {
+                                break;
+                            }
+                            let (enzyme_strand, target) = {
+                                let soup = soup.read().expect("Soup lock poisoned");
+                                Self::sample_pair(&soup.strands, &mut rng)
+                            };
+                            let Some(reaction) =
+                                Self::rewrite_pair(enzyme_strand, target, &mut rng, cache)
+                            else {
+                                continue;
+                            };
+                            n_rewrites.fetch_add(1, Ordering::Relaxed);
+
+                            // Only take the write lock when there is something new
+                            let has_new = {
+                                let soup = soup.read().expect("Soup lock poisoned");
+                                reaction
+                                    .products
+                                    .iter()
+                                    .any(|s| !soup.known_set.contains(s))
+                            };
+                            if has_new {
+                                let mut soup = soup.write().expect("Soup lock poisoned");
+                                let mut counts = counts.lock().expect("Limit lock poisoned");
+                                for strand in &reaction.products {
+                                    match Self::admit(
+                                        &mut soup,
+                                        strand.clone(),
+                                        limits,
+                                        &mut counts,
+                                    ) {
+                                        Err(limit) => {
+                                            stop_at_limit(&mut counts, limit, iteration + 1)
+                                        }
+                                        Ok(false) => {}
+                                        Ok(true) => {
+                                            last_new_iteration
+                                                .fetch_max(iteration + 1, Ordering::Relaxed);
+                                            stop(Self::find_strand_stop(
+                                                stop_conditions,
+                                                iteration + 1,
+                                                strand,
+                                                Some(&reaction),
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().expect("Simulation worker panicked");
+            }
+        });
+
+        let counts = counts.into_inner().expect("Limit lock poisoned");
+        let stop = stop_event.into_inner().expect("Stop lock poisoned");
+        SearchResult {
+            strands: soup.into_inner().expect("Soup lock poisoned").strands,
+            n_iterations: stop
+                .as_ref()
+                .map(|stop| stop.iteration)
+                .or(counts.stopped_at)
+                .unwrap_or(n_iterations),
+            n_rewrites: n_rewrites.into_inner(),
+            elapsed: start.elapsed(),
+            limits: counts,
+            stop,
+        }
+    }
+
+    fn run_rounds(
+        init_strand: &Strand,
+        n_iterations: usize,
+        seed: u64,
+        caches: &mut [RewriteCache],
+        limits: &ResourceLimits,
+        stop_conditions: &[StopCondition],
+        start: Instant,
+    ) -> SearchResult {
+        let mut soup = Soup::new(init_strand);
+        let mut counts = LimitCounts::new(limits.policy);
+        let mut n_rewrites = 0;
+        let mut n_done = 0;
+        let mut last_new_iteration = 0;
+        let mut stop_event = None;
+
+        while n_done < n_iterations && stop_event.is_none() && counts.stopped_by.is_none() {
+            let n_round = Self::ROUND_SIZE.min(n_iterations - n_done);
+            let chunk_size = n_round.div_ceil(caches.len());
+            let strands = &soup.strands;
+
+            let results: Vec<Option<Reaction>> = thread::scope(|scope| {
+                let handles: Vec<_> = caches
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(worker, cache)| {
+                        let start = n_done + (worker * chunk_size).min(n_round);
+                        let end = n_done + ((worker + 1) * chunk_size).min(n_round);
+                        scope.spawn(move || {
+                            (start..end)
+                                .map(|iteration| {
+                                    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                                    rng.set_stream(iteration as u64);
+                                    let (enzyme_strand, target) =
+                                        Self::sample_pair(strands, &mut rng);
+                                    Self::rewrite_pair(enzyme_strand, target, &mut rng, cache)
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+
+                // Chunks are in iteration order, so products are merged in that order
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().expect("Simulation worker panicked"))
+                    .collect()
+            });
+
+            // A stop discards the rest of the round, as if it had never run
+            for (iteration, reaction) in (n_done..).zip(results) {
+                if let Some(limit) = Self::find_run_limit(limits, start, n_rewrites) {
+                    counts.fire(limit);
+                    counts.stop(limit, iteration);
+                    break;
+                }
+                stop_event = Self::find_stagnation(stop_conditions, iteration, last_new_iteration);
+                if stop_event.is_some() {
+                    break;
+                }
+                n_done = iteration + 1;
+                let Some(reaction) = reaction else {
+                    continue;
+                };
+                n_rewrites += 1;
+                let mut stop = None;
+                for strand in &reaction.products {
+                    match Self::admit(&mut soup, strand.clone(), limits, &mut counts) {
+                        Err(limit) => stop = Some(limit),
+                        Ok(false) => {}
+                        Ok(true) => {
+                            last_new_iteration = n_done;
+                            if stop_event.is_none() {
+                                stop_event = Self::find_strand_stop(
+                                    stop_conditions,
+                                    n_done,
+                                    strand,
+                                    Some(&reaction),
+                                );
+                            }
+                        }
+                    }
+                }
+                if let Some(limit) = stop {
+                    counts.stop(limit, n_done);
+                }
+                if stop.is_some() || stop_event.is_some() {
+                    break;
+                }
+            }
+        }
+
+        SearchResult {
+            strands: soup.strands,
+            n_iterations: n_done,
+            n_rewrites,
+            elapsed: start.elapsed(),
+            limits: counts,
+            stop: stop_event,
+        }
+    }
+
+    /// Pick the strand to translate into an enzyme and the strand to rewrite with it.
+    fn sample_pair(strands: &[Strand], rng: &mut ChaCha8Rng) -> (Strand, Strand) {
+        let enzyme_strand = &strands[rng.gen_range(0..strands.len())];
+        let target = &strands[rng.gen_range(0..strands.len())];
+        (enzyme_strand.clone(), target.clone())
+    }
+
+    /// Rewrite the target with a random enzyme of the enzyme strand, if it codes any.
+    fn rewrite_pair(
+        enzyme_strand: Strand,
+        target: Strand,
+        rng: &mut ChaCha8Rng,
+        cache: &mut RewriteCache,
+    ) -> Option<Reaction> {
+        let enzymes = cache.translate(&enzyme_strand);
+        if enzymes.is_empty() {
+            return None;
+        }
+        let enzyme = enzymes[rng.gen_range(0..enzymes.len())].clone();
+        let products = cache.rewrite(&enzyme, &target);
+        Some(Reaction {
+            catalyst: enzyme_strand,
+            enzyme,
+            reactant: target,
+            products,
+        })
+    }
+
+    fn print_results(strands: &[Strand], summary: &str, print_strands: Option<&StrandOutput>) {
+        let mut sorted_strands: Vec<String> = strands
+            .iter()
+            .filter(|s| print_strands.is_some_and(|output| output.is_selected(s)))
+            .map(|s| format!("{:?}", s))
+            .collect();
+        sorted_strands.sort();
+        if let Some(filter) = print_strands.and_then(|output| output.filter.as_ref()) {
+            log::info!(
+                "{} of {} strands match {}",
+                sorted_strands.len(),
+                strands.len(),
+                filter
+            );
+        }
+
+        match print_strands.map(|output| output.format) {
+            Some(StrandFormat::Plain) => {
+                println!("Unique strands:");
+                for strand_str in sorted_strands {
+                    println!("- {}", strand_str);
+                }
+            }
+            Some(StrandFormat::Fasta) => {
+                let records: Vec<FastaRecord> = sorted_strands
+                    .iter()
+                    .enumerate()
+                    .map(|(i, strand_str)| FastaRecord {
+                        id: format!("strand_{}", i + 1),
+                        description: String::new(),
+                        strand: Strand::from_string(strand_str),
+                    })
+                    .collect();
+                FastaWriter::write(&mut std::io::stdout().lock(), &records)
+                    .expect("Failed to write strands");
+                // Keep stdout valid FASTA
+                eprintln!("{}", summary);
+                return;
+            }
+            None => {}
+        }
+
+        println!("{}", summary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::StandardRules;
+    use crate::typogenetics::Motif;
+
+    fn run_stagnating(
+        checkpoint: Checkpoint,
+        n_iterations: usize,
+        schedule: Option<&CheckpointSchedule>,
+    ) -> SearchResult {
+        SearchAlgos::resume_random(
+            checkpoint,
+            n_iterations,
+            &mut StandardRules,
+            schedule,
+            &ResourceLimits::unlimited(),
+            &[StopCondition::Stagnation(50)],
+            &mut (),
+        )
+        .unwrap()
+    }
+
+    fn start() -> Checkpoint {
+        Checkpoint::start(
+            &[Strand::from_string("CAAAGAGAATCCTCTTTGAT")],
+            CheckpointState::Random {
+                rng: Box::new(ChaCha8Rng::seed_from_u64(2)),
+                last_new_iteration: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_run() {
+        let uninterrupted = run_stagnating(start(), 10_000, None);
+        let stop = uninterrupted.stop.as_ref().unwrap().iteration;
+
+        // Interrupt the run while it stagnates, one iteration before it stops
+        let path = std::env::temp_dir().join(format!("resume-{}.json", std::process::id()));
+        let schedule = CheckpointSchedule {
+            path: String::from(path.to_str().unwrap()),
+            every: stop,
+        };
+        let interrupted = run_stagnating(start(), stop - 1, Some(&schedule));
+        assert!(interrupted.stop.is_none());
+        let checkpoint = Checkpoint::load(&schedule.path).unwrap();
+        std::fs::remove_file(&schedule.path).unwrap();
+        let resumed = run_stagnating(checkpoint, 10_000, None);
+
+        assert_eq!(resumed.stop.unwrap().iteration, stop);
+        assert_eq!(resumed.strands, uninterrupted.strands);
+        assert_eq!(resumed.n_rewrites, uninterrupted.n_rewrites);
+    }
+
+    fn pattern(motif: &str) -> Vec<StopCondition> {
+        vec![StopCondition::Pattern(
+            Motif::try_from_string(motif).unwrap(),
+        )]
+    }
+
+    #[test]
+    fn initial_target_stops_every_search_at_once() {
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let target = [StopCondition::Target(strand.clone())];
+        let random = SearchAlgos::resume_random(
+            start(),
+            1000,
+            &mut StandardRules,
+            None,
+            &ResourceLimits::unlimited(),
+            &[StopCondition::Target(Strand::from_string(
+                "CAAAGAGAATCCTCTTTGAT",
+            ))],
+            &mut (),
+        )
+        .unwrap();
+        assert_eq!(random.stop.unwrap().iteration, 0);
+        assert_eq!(random.n_rewrites, 0);
+
+        let checkpoint = Checkpoint::start(
+            std::slice::from_ref(&strand),
+            CheckpointState::Generational { n_done: 0 },
+        );
+        let limits = GenerationLimits::new(5, 1000, 100);
+        let generational = SearchAlgos::resume_generations(
+            checkpoint,
+            limits,
+            &mut RewriteCache::new(0),
+            None,
+            &target,
+            |_| panic!("No generation should run"),
+            |_, _, _, _| {},
+        )
+        .unwrap();
+        assert_eq!(generational.stop.unwrap().iteration, 0);
+        assert_eq!(generational.strands, vec![strand.clone()]);
+
+        let config = ParallelConfig {
+            n_threads: 2,
+            seed: 2,
+            deterministic: false,
+            cache_capacity: 0,
+        };
+        let (parallel, _) =
+            SearchAlgos::run_parallel(&strand, 1000, config, &ResourceLimits::unlimited(), &target);
+        assert_eq!(parallel.stop.unwrap().iteration, 0);
+        assert_eq!(parallel.n_iterations, 0);
+    }
+
+    #[test]
+    fn generational_search_stops_after_generation_meeting_condition() {
+        let checkpoint = Checkpoint::start(
+            &[Strand::from_string("ATAGCGAATAGGATAATG")],
+            CheckpointState::Generational { n_done: 0 },
+        );
+        let limits = GenerationLimits::new(10, 10_000, 100);
+        let mut n_generations = 0;
+        let result = SearchAlgos::resume_generations(
+            checkpoint,
+            limits,
+            &mut RewriteCache::new(0),
+            None,
+            &pattern("GGGG"),
+            |_| n_generations += 1,
+            |_, _, _, _| {},
+        )
+        .unwrap();
+
+        let stop = result.stop.unwrap();
+        assert_eq!(stop.iteration, n_generations);
+        let strand = stop.strand.unwrap();
+        assert!(result.strands.contains(&strand));
+        assert!(stop.reaction.unwrap().products.contains(&strand));
+    }
+
+    #[test]
+    fn deterministic_threads_stop_at_same_iteration() {
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let run = |n_threads| {
+            let config = ParallelConfig {
+                n_threads,
+                seed: 2,
+                deterministic: true,
+                cache_capacity: 0,
+            };
+            SearchAlgos::run_parallel(
+                &strand,
+                100_000,
+                config,
+                &ResourceLimits::unlimited(),
+                &pattern("GGGG"),
+            )
+            .0
+        };
+        let one = run(1);
+        let three = run(3);
+
+        let stop = one.stop.unwrap().iteration;
+        assert!(stop < 100_000);
+        assert_eq!(one.n_iterations, stop);
+        assert_eq!(three.stop.unwrap().iteration, stop);
+        assert_eq!(three.strands, one.strands);
+    }
+
+    #[test]
+    fn replicates_each_stop_on_their_own() {
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let summary = SearchAlgos::replicates(
+            &strand,
+            100_000,
+            3,
+            2,
+            0,
+            None,
+            &ResourceLimits::unlimited(),
+            &pattern("GGGG"),
+            None,
+        );
+
+        assert_eq!(summary.n_stopped, 3);
+        let (_, iterations) = summary
+            .metrics
+            .iter()
+            .find(|(name, _)| *name == "iterations")
+            .unwrap();
+        assert!(iterations.mean < 100_000.0);
+    }
+
+    #[test]
+    fn deterministic_threads_stop_at_same_limit() {
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let limits = ResourceLimits {
+            max_population: Some(100),
+            policy: LimitPolicy::Stop,
+            ..ResourceLimits::unlimited()
+        };
+        let run = |n_threads| {
+            let config = ParallelConfig {
+                n_threads,
+                seed: 2,
+                deterministic: true,
+                cache_capacity: 0,
+            };
+            SearchAlgos::run_parallel(&strand, 100_000, config, &limits, &[]).0
+        };
+        let one = run(1);
+        let three = run(3);
+
+        assert_eq!(one.strands.len(), 100);
+        assert_eq!(one.limits.stopped_by, Some(Limit::Population));
+        assert_eq!(one.limits.stopped_at, Some(one.n_iterations));
+        assert_eq!(three.limits, one.limits);
+        assert_eq!(three.strands, one.strands);
+    }
+
+    #[test]
+    fn generational_search_evicts_to_stay_under_population_limit() {
+        let limits = GenerationLimits {
+            max_generations: 6,
+            resources: ResourceLimits {
+                max_population: Some(50),
+                policy: LimitPolicy::Evict,
+                ..ResourceLimits::unlimited()
+            },
+        };
+        let strand = Strand::from_string("ATAGCGAATAGGATAATG");
+        let checkpoint = Checkpoint::start(
+            std::slice::from_ref(&strand),
+            CheckpointState::Generational { n_done: 0 },
+        );
+        let mut summaries = Vec::new();
+        let result = SearchAlgos::resume_generations(
+            checkpoint,
+            limits,
+            &mut RewriteCache::new(0),
+            None,
+            &[],
+            |summary| summaries.push(*summary),
+            |_, _, _, _| {},
+        )
+        .unwrap();
+
+        assert!(summaries.iter().all(|summary| summary.n_strands <= 50));
+        let n_evicted: usize = summaries.iter().map(|summary| summary.n_evicted).sum();
+        assert!(n_evicted > 0);
+        assert_eq!(result.limits.get_n_evicted(), n_evicted);
+        assert_eq!(result.strands[0], strand);
+    }
+
+    #[test]
+    fn generational_rewrite_limit_leaves_generation_to_repeat() {
+        let limits = GenerationLimits {
+            max_generations: 10,
+            resources: ResourceLimits {
+                max_rewrites: Some(1000),
+                ..GenerationLimits::new(10, 10_000, 100).resources
+            },
+        };
+        let checkpoint = Checkpoint::start(
+            &[Strand::from_string("ATAGCGAATAGGATAATG")],
+            CheckpointState::Generational { n_done: 0 },
+        );
+        let path = std::env::temp_dir().join(format!("generations-{}.json", std::process::id()));
+        let schedule = CheckpointSchedule {
+            path: String::from(path.to_str().unwrap()),
+            every: 100,
+        };
+        let result = SearchAlgos::resume_generations(
+            checkpoint,
+            limits,
+            &mut RewriteCache::new(0),
+            Some(&schedule),
+            &[],
+            |_| {},
+            |_, _, _, _| {},
+        )
+        .unwrap();
+        let saved = Checkpoint::load(&schedule.path).unwrap();
+        std::fs::remove_file(&schedule.path).unwrap();
+
+        assert_eq!(result.n_rewrites, 1000);
+        assert_eq!(result.limits.stopped_by, Some(Limit::Rewrites));
+        assert_eq!(result.limits.stopped_at, Some(result.n_iterations));
+        assert_eq!(saved.iteration, result.n_iterations - 1);
+        assert_eq!(saved.strands, result.strands);
+    }
+
+    fn limited(policy: LimitPolicy) -> ResourceLimits {
+        ResourceLimits {
+            max_strand_len: Some(6),
+            max_total_bases: Some(10),
+            policy,
+            ..ResourceLimits::unlimited()
+        }
+    }
+
+    fn soup(strands: &[&str]) -> Soup {
+        Soup::from_strands(strands.iter().map(|s| Strand::from_string(s)).collect())
+    }
+
+    #[test]
+    fn admit_drops_under_drop_policy() {
+        let limits = limited(LimitPolicy::Drop);
+        let mut soup = soup(&["ATAG", "CGA"]);
+        let mut counts = LimitCounts::new(limits.policy);
+
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, Strand::from_string("TTT"), &limits, &mut counts),
+            Ok(true)
+        );
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, Strand::from_string("TT"), &limits, &mut counts),
+            Ok(false)
+        );
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, Strand::from_string("TTT"), &limits, &mut counts),
+            Ok(false)
+        );
+        assert_eq!(soup.strands.len(), 3);
+        assert_eq!(counts.get_count(Limit::TotalBases), 1);
+    }
+
+    #[test]
+    fn admit_stops_under_stop_policy() {
+        let limits = limited(LimitPolicy::Stop);
+        let mut soup = soup(&["ATAG", "CGAT"]);
+        let mut counts = LimitCounts::new(limits.policy);
+
+        let long = Strand::from_string("AAAAAAA");
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, long, &limits, &mut counts),
+            Err(Limit::StrandLength)
+        );
+        let short = Strand::from_string("CCC");
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, short, &limits, &mut counts),
+            Err(Limit::TotalBases)
+        );
+        assert_eq!(soup.strands.len(), 2);
+    }
+
+    #[test]
+    fn admit_evicts_oldest_under_evict_policy() {
+        let limits = limited(LimitPolicy::Evict);
+        let mut soup = soup(&["ATAG", "CG", "GGG"]);
+        let mut counts = LimitCounts::new(limits.policy);
+
+        let product = Strand::from_string("TTTT");
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, product, &limits, &mut counts),
+            Ok(true)
+        );
+        assert_eq!(
+            soup.strands,
+            vec![Strand::from_string("ATAG"), Strand::from_string("TTTT")]
+        );
+        assert_eq!(soup.total_bases, 8);
+        assert_eq!(counts.get_n_evicted(), 2);
+    }
+
+    #[test]
+    fn admit_keeps_soup_for_product_that_never_fits() {
+        let limits = ResourceLimits {
+            max_total_bases: Some(10),
+            policy: LimitPolicy::Evict,
+            ..ResourceLimits::unlimited()
+        };
+        let mut soup = soup(&["ATAG", "CG", "GG"]);
+        let mut counts = LimitCounts::new(limits.policy);
+
+        let product = Strand::from_string("TTTTTTT");
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, product, &limits, &mut counts),
+            Ok(false)
+        );
+        assert_eq!(soup.strands.len(), 3);
+        assert_eq!(counts.get_count(Limit::TotalBases), 1);
+        assert_eq!(counts.get_n_evicted(), 0);
+    }
+
+    #[test]
+    fn admit_evicts_for_population() {
+        let limits = ResourceLimits {
+            max_population: Some(2),
+            policy: LimitPolicy::Evict,
+            ..ResourceLimits::unlimited()
+        };
+        let mut soup = soup(&["ATAG", "CG"]);
+        let mut counts = LimitCounts::new(limits.policy);
+
+        assert_eq!(
+            SearchAlgos::admit(&mut soup, Strand::from_string("TT"), &limits, &mut counts),
+            Ok(true)
+        );
+        assert_eq!(
+            soup.strands,
+            vec![Strand::from_string("ATAG"), Strand::from_string("TT")]
         );
+        assert_eq!(counts.get_n_evicted(), 1);
     }
 }
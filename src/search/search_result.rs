@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use crate::search::{LimitCounts, StopEvent};
+use crate::typogenetics::Strand;
+
+/// The outcome of one simulation run.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    /// Every strand discovered, in the order it was found.
+    pub strands: Vec<Strand>,
+    pub n_iterations: usize,
+    /// Iterations that rewrote a strand, as opposed to drawing a strand without enzymes.
+    pub n_rewrites: usize,
+    pub elapsed: Duration,
+    pub limits: LimitCounts,
+    /// The stop condition that ended the search early, if any.
+    pub stop: Option<StopEvent>,
+}
+
+impl SearchResult {
+    pub fn get_rewrites_per_second(&self) -> f64 {
+        self.n_rewrites as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
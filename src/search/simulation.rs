@@ -0,0 +1,48 @@
+use rand_chacha::ChaCha8Rng;
+
+use crate::search::{
+    Checkpoint, CheckpointState, Observer, ResourceLimits, Rules, SearchAlgos, SearchResult,
+    SimulationBuilder, StopCondition,
+};
+use crate::typogenetics::Strand;
+
+/// A random simulation ready to run, for embedding the engine in other programs. Each
+/// iteration rewrites a random strand with a random enzyme of a random strand, as in
+/// `SearchAlgos::random`, but nothing is printed and the result is returned.
+///
+/// Build one with `Simulation::builder`.
+pub struct Simulation<'a> {
+    pub(crate) init_strands: Vec<Strand>,
+    pub(crate) n_iterations: usize,
+    pub(crate) rules: Box<dyn Rules + 'a>,
+    pub(crate) rng: ChaCha8Rng,
+    pub(crate) limits: ResourceLimits,
+    pub(crate) stop_conditions: Vec<StopCondition>,
+    pub(crate) observers: Vec<Box<dyn Observer + 'a>>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn builder(init_strands: &[Strand]) -> SimulationBuilder<'a> {
+        SimulationBuilder::new(init_strands)
+    }
+
+    pub fn run(mut self) -> SearchResult {
+        let checkpoint = Checkpoint::start(
+            &self.init_strands,
+            CheckpointState::Random {
+                rng: Box::new(self.rng),
+                last_new_iteration: 0,
+            },
+        );
+        SearchAlgos::resume_random(
+            checkpoint,
+            self.n_iterations,
+            self.rules.as_mut(),
+            None,
+            &self.limits,
+            &self.stop_conditions,
+            &mut self.observers,
+        )
+        .expect("Searches without checkpoints cannot fail")
+    }
+}
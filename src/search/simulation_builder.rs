@@ -0,0 +1,81 @@
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+use crate::search::{
+    Observer, ResourceLimits, RewriteCache, Rules, Simulation, StandardRules, StopCondition,
+};
+use crate::typogenetics::Strand;
+
+/// Configures a `Simulation`. By default it runs 100,000 iterations of the standard
+/// rules without caching, seeded with 2 like the CLI, with no limits, stop conditions
+/// or observers.
+pub struct SimulationBuilder<'a> {
+    simulation: Simulation<'a>,
+}
+
+impl<'a> SimulationBuilder<'a> {
+    pub fn new(init_strands: &[Strand]) -> SimulationBuilder<'a> {
+        SimulationBuilder {
+            simulation: Simulation {
+                init_strands: init_strands.to_vec(),
+                n_iterations: 100_000,
+                rules: Box::new(StandardRules),
+                rng: ChaCha8Rng::seed_from_u64(2),
+                limits: ResourceLimits::unlimited(),
+                stop_conditions: Vec::new(),
+                observers: Vec::new(),
+            },
+        }
+    }
+
+    pub fn iterations(mut self, n_iterations: usize) -> Self {
+        self.simulation.n_iterations = n_iterations;
+        self
+    }
+
+    pub fn rules(mut self, rules: impl Rules + 'a) -> Self {
+        self.simulation.rules = Box::new(rules);
+        self
+    }
+
+    /// Use the standard rules with translations and rewrites cached, see `RewriteCache`.
+    pub fn cache_capacity(self, capacity: usize) -> Self {
+        self.rules(RewriteCache::new(capacity))
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.simulation.rng = ChaCha8Rng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn rng(mut self, rng: ChaCha8Rng) -> Self {
+        self.simulation.rng = rng;
+        self
+    }
+
+    pub fn limits(mut self, limits: ResourceLimits) -> Self {
+        self.simulation.limits = limits;
+        self
+    }
+
+    /// Stop the run when the condition is met. Conditions added earlier take precedence
+    /// when several are met by the same iteration.
+    pub fn stop_when(mut self, condition: StopCondition) -> Self {
+        self.simulation.stop_conditions.push(condition);
+        self
+    }
+
+    /// Send events of the run to an observer. Pass `&mut observer` to read it after the
+    /// run.
+    pub fn observer(mut self, observer: impl Observer + 'a) -> Self {
+        self.simulation.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Fails if there are no initial strands.
+    pub fn build(self) -> Result<Simulation<'a>, String> {
+        if self.simulation.init_strands.is_empty() {
+            return Err(String::from("Simulation needs at least one initial strand"));
+        }
+        Ok(self.simulation)
+    }
+}
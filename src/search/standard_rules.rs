@@ -0,0 +1,16 @@
+use crate::search::Rules;
+use crate::typogenetics::{Enzyme, Rewriter, Strand, Translator};
+
+/// The rules of `Translator` and `Rewriter`, without caching.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StandardRules;
+
+impl Rules for StandardRules {
+    fn translate(&mut self, strand: &Strand) -> Vec<Enzyme> {
+        Translator::translate(strand)
+    }
+
+    fn rewrite(&mut self, enzyme: &Enzyme, strand: &Strand) -> Vec<Strand> {
+        Rewriter::rewrite(enzyme, strand)
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analysis::SelfApplier;
+use crate::search::FitnessMetric;
+use crate::typogenetics::{Motif, Strand};
+
+/// A reason for a search to stop before running all of its iterations.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum StopCondition {
+    /// A particular strand is discovered.
+    Target(Strand),
+    /// A strand containing a motif is discovered.
+    Pattern(Motif),
+    /// A strand for which `SelfApplier::is_self_replicator` holds is discovered.
+    SelfReplicator,
+    /// No new strand is discovered for this many iterations.
+    Stagnation(usize),
+    /// A strand scoring at least the threshold on a metric is discovered.
+    Fitness {
+        metric: FitnessMetric,
+        threshold: usize,
+    },
+}
+
+impl StopCondition {
+    /// Whether discovering this strand meets the condition. Stagnation is met by the
+    /// absence of new strands, so never by a strand.
+    pub fn is_met_by(&self, strand: &Strand) -> bool {
+        match self {
+            StopCondition::Target(target) => strand == target,
+            StopCondition::Pattern(motif) => strand.contains_motif(motif),
+            StopCondition::SelfReplicator => SelfApplier::is_self_replicator(strand),
+            StopCondition::Stagnation(_) => false,
+            StopCondition::Fitness { metric, threshold } => metric.evaluate(strand) >= *threshold,
+        }
+    }
+}
+
+impl Display for StopCondition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            StopCondition::Target(target) => write!(f, "discovered target {}", target),
+            StopCondition::Pattern(pattern) => {
+                write!(f, "discovered a strand containing {}", pattern)
+            }
+            StopCondition::SelfReplicator => write!(f, "discovered a self-replicator"),
+            StopCondition::Stagnation(n) => {
+                write!(f, "no new strand for {} iterations", n)
+            }
+            StopCondition::Fitness { metric, threshold } => {
+                write!(f, "discovered a strand with {} >= {}", metric, threshold)
+            }
+        }
+    }
+}
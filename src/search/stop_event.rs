@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analysis::Reaction;
+use crate::search::StopCondition;
+use crate::typogenetics::Strand;
+
+/// The stop condition that ended a search, with where it happened.
+#[derive(Debug, Clone)]
+pub struct StopEvent {
+    pub condition: StopCondition,
+    /// Number of iterations, or generations of a generational search, completed when the
+    /// search stopped.
+    pub iteration: usize,
+    /// The strand that met the condition, if it was met by a strand.
+    pub strand: Option<Strand>,
+    /// The reaction that produced the strand.
+    pub reaction: Option<Reaction>,
+}
+
+impl Display for StopEvent {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Stopped at iteration {}: {}",
+            self.iteration, self.condition
+        )?;
+        if let Some(strand) = &self.strand {
+            write!(f, "\nStrand: {}", strand)?;
+        }
+        if let Some(reaction) = &self.reaction {
+            write!(f, "\nReaction: {}", reaction)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+/// How discovered strands are printed at the end of a search.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StrandFormat {
+    Plain,
+    Fasta,
+}
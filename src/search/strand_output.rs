@@ -0,0 +1,26 @@
+use crate::query::Filter;
+use crate::search::StrandFormat;
+use crate::typogenetics::Strand;
+
+/// Which discovered strands are printed at the end of a search, and how.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StrandOutput {
+    pub format: StrandFormat,
+    /// Only strands matching the filter are printed. The summary still counts every strand.
+    pub filter: Option<Filter>,
+}
+
+impl StrandOutput {
+    pub fn new(format: StrandFormat) -> StrandOutput {
+        StrandOutput {
+            format,
+            filter: None,
+        }
+    }
+
+    pub fn is_selected(&self, strand: &Strand) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(strand))
+    }
+}
@@ -0,0 +1,5 @@
+mod svg_document;
+mod svg_renderer;
+
+pub use svg_document::SvgDocument;
+pub use svg_renderer::SvgRenderer;
@@ -0,0 +1,93 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// A minimal builder for self-contained SVG documents.
+pub struct SvgDocument {
+    width: f64,
+    height: f64,
+    elements: Vec<String>,
+}
+
+impl SvgDocument {
+    pub fn new(width: f64, height: f64) -> SvgDocument {
+        SvgDocument {
+            width,
+            height,
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn get_width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, fill: &str, stroke: &str) {
+        self.elements.push(format!(
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}" stroke="{}"/>"#,
+            x, y, width, height, fill, stroke
+        ));
+    }
+
+    pub fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, stroke: &str, stroke_width: f64) {
+        self.elements.push(format!(
+            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{:.1}" stroke-linecap="round"/>"#,
+            x1, y1, x2, y2, stroke, stroke_width
+        ));
+    }
+
+    pub fn circle(&mut self, cx: f64, cy: f64, r: f64, fill: &str, stroke: &str) {
+        self.elements.push(format!(
+            r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}" stroke="{}"/>"#,
+            cx, cy, r, fill, stroke
+        ));
+    }
+
+    pub fn text(&mut self, x: f64, y: f64, text: &str, size: f64, fill: &str, anchor: &str) {
+        self.elements.push(format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" fill="{}" text-anchor="{}" dominant-baseline="central">{}</text>"#,
+            x,
+            y,
+            size,
+            fill,
+            anchor,
+            Self::escape(text)
+        ));
+    }
+
+    /// Embed another document with its origin at the given position.
+    pub fn embed(&mut self, x: f64, y: f64, document: SvgDocument) {
+        self.elements
+            .push(format!(r#"<g transform="translate({:.1} {:.1})">"#, x, y));
+        self.elements.extend(document.elements);
+        self.elements.push(String::from("</g>"));
+    }
+
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+impl Display for SvgDocument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.1} {:.1}" font-family="monospace">"#,
+            self.width, self.height, self.width, self.height
+        )?;
+        writeln!(
+            f,
+            r#"<rect x="0" y="0" width="{:.1}" height="{:.1}" fill="white"/>"#,
+            self.width, self.height
+        )?;
+        for element in &self.elements {
+            writeln!(f, "{}", element)?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
@@ -0,0 +1,290 @@
+use crate::svg::SvgDocument;
+use crate::typogenetics::{BasePair, Enzyme, Folder, RewriteState, Rewriter, Strand, Turn};
+
+/// Draws enzymes, strands and rewrite traces as SVG documents.
+pub struct SvgRenderer;
+
+impl SvgRenderer {
+    const MARGIN: f64 = 20.0;
+    const CELL: f64 = 20.0;
+    const LATTICE: f64 = 64.0;
+    const NODE_RADIUS: f64 = 17.0;
+    const FONT_SIZE: f64 = 13.0;
+    const CHAR_WIDTH: f64 = 8.0;
+    const TITLE_HEIGHT: f64 = 28.0;
+
+    /// Draw the tertiary structure of an enzyme along `Folder::fold_path`, with the
+    /// final heading pointing at the binding base. Amino acids that collide with
+    /// another are outlined in red.
+    pub fn render_enzyme(enzyme: &Enzyme) -> SvgDocument {
+        let fold_path = Folder::fold_path(enzyme);
+        let points = &fold_path.points;
+        let intersections = fold_path.get_intersections();
+        let binding_base = Folder::get_binding_affinity(fold_path.orientation);
+        let (dx, dy) = fold_path.orientation.to_vector();
+        let (dx, dy) = (dx as f64, dy as f64);
+
+        let last = points.last().copied().unwrap_or((0, 0));
+        let arrow_end = (last.0 as f64 + dx * 0.8, last.1 as f64 + dy * 0.8);
+        let stub_start = (-0.6, 0.0);
+
+        let xs = points
+            .iter()
+            .map(|p| p.0 as f64)
+            .chain([arrow_end.0, stub_start.0]);
+        let ys = points
+            .iter()
+            .map(|p| p.1 as f64)
+            .chain([arrow_end.1, stub_start.1]);
+        let min_x = xs.clone().fold(f64::INFINITY, f64::min);
+        let max_x = xs.fold(f64::NEG_INFINITY, f64::max);
+        let min_y = ys.clone().fold(f64::INFINITY, f64::min);
+        let max_y = ys.fold(f64::NEG_INFINITY, f64::max);
+
+        let pad = Self::MARGIN + Self::NODE_RADIUS;
+        let title = format!("{} binds {}", enzyme, binding_base);
+        let width = ((max_x - min_x) * Self::LATTICE + 2.0 * pad)
+            .max(title.len() as f64 * Self::CHAR_WIDTH + 2.0 * Self::MARGIN);
+        let height = (max_y - min_y) * Self::LATTICE + 2.0 * pad + Self::TITLE_HEIGHT;
+        let to_screen = |x: f64, y: f64| {
+            (
+                pad + (x - min_x) * Self::LATTICE,
+                pad + Self::TITLE_HEIGHT + (y - min_y) * Self::LATTICE,
+            )
+        };
+
+        let mut document = SvgDocument::new(width, height);
+        document.text(
+            Self::MARGIN,
+            Self::MARGIN,
+            &title,
+            Self::FONT_SIZE,
+            "black",
+            "start",
+        );
+
+        let (sx, sy) = to_screen(stub_start.0, stub_start.1);
+        let (fx, fy) = to_screen(0.0, 0.0);
+        document.line(sx, sy, fx, fy, "#bbbbbb", 3.0);
+
+        for pair in points.windows(2) {
+            let (x1, y1) = to_screen(pair[0].0 as f64, pair[0].1 as f64);
+            let (x2, y2) = to_screen(pair[1].0 as f64, pair[1].1 as f64);
+            document.line(x1, y1, x2, y2, "#555555", 3.0);
+        }
+
+        let (lx, ly) = to_screen(last.0 as f64, last.1 as f64);
+        let (ax, ay) = to_screen(arrow_end.0, arrow_end.1);
+        document.line(lx, ly, ax, ay, "#d62728", 3.0);
+        document.circle(ax, ay, Self::NODE_RADIUS * 0.7, "#d62728", "#d62728");
+        document.text(
+            ax,
+            ay,
+            &binding_base.to_string(),
+            Self::FONT_SIZE,
+            "white",
+            "middle",
+        );
+
+        for (point, amino_acid) in points.iter().zip(enzyme.iter_amino_acids()) {
+            let (x, y) = to_screen(point.0 as f64, point.1 as f64);
+            let fill = match Folder::get_turn(amino_acid) {
+                Turn::L => "#c6dbef",
+                Turn::S => "#ffffff",
+                Turn::R => "#fdd0a2",
+            };
+            let stroke = if intersections.contains(point) {
+                "#d62728"
+            } else {
+                "#333333"
+            };
+            document.circle(x, y, Self::NODE_RADIUS, fill, stroke);
+            document.text(
+                x,
+                y,
+                &amino_acid.to_string(),
+                Self::FONT_SIZE - 2.0,
+                "black",
+                "middle",
+            );
+        }
+
+        document
+    }
+
+    /// Draw a strand paired with its full complement.
+    pub fn render_strand(strand: &Strand) -> SvgDocument {
+        let pairs: Vec<BasePair> = strand
+            .iter_bases()
+            .map(|&base| BasePair {
+                bind: Some(base),
+                comp: Some(base.get_complement()),
+            })
+            .collect();
+
+        let title = strand.to_string();
+        let width =
+            Self::text_width(&title).max(pairs.len() as f64 * Self::CELL) + 2.0 * Self::MARGIN;
+        let height = Self::TITLE_HEIGHT + 2.0 * Self::CELL + 2.0 * Self::MARGIN;
+
+        let mut document = SvgDocument::new(width, height);
+        document.text(
+            Self::MARGIN,
+            Self::MARGIN,
+            &title,
+            Self::FONT_SIZE,
+            "black",
+            "start",
+        );
+        Self::draw_pairs(
+            &mut document,
+            Self::MARGIN,
+            Self::MARGIN + Self::TITLE_HEIGHT,
+            &pairs,
+            None,
+        );
+        document
+    }
+
+    /// Draw every state of a rewrite as a sequence of frames from top to bottom.
+    pub fn render_trace(enzyme: &Enzyme, strand: &Strand) -> SvgDocument {
+        let states = Rewriter::trace(enzyme, strand);
+        let amino_acids: Vec<String> = enzyme.iter_amino_acids().map(|a| a.to_string()).collect();
+
+        let mut frames = Vec::new();
+        if states.is_empty() {
+            let mut lines = vec![format!("{} has no binding site on {}", enzyme, strand)];
+            lines.push(format!("products: {}", strand));
+            frames.push(Self::render_frame(&lines, None));
+        }
+        for (i, state) in states.iter().enumerate() {
+            let mut lines = vec![if i == 0 {
+                format!("{} binds {} at unit {}", enzyme, strand, state.unit)
+            } else {
+                format!("step {}: {}", i, amino_acids[state.step - 1])
+            }];
+            if let Some(halt) = state.halt {
+                lines.push(format!("halted: {}", halt));
+            }
+            if !state.strands.is_empty() {
+                lines.push(format!("cut: {}", Self::join_strands(&state.strands)));
+            }
+            if i + 1 == states.len() {
+                let products = Rewriter::get_products(state);
+                lines.push(format!("products: {}", Self::join_strands(&products)));
+            }
+            frames.push(Self::render_frame(&lines, Some(state)));
+        }
+
+        let width = frames.iter().map(|f| f.get_width()).fold(0.0, f64::max);
+        let height = frames.iter().map(|f| f.get_height()).sum::<f64>() + Self::MARGIN;
+        let mut document = SvgDocument::new(width, height);
+        let mut y = 0.0;
+        for frame in frames {
+            let frame_height = frame.get_height();
+            document.embed(0.0, y, frame);
+            y += frame_height;
+            if y < height - Self::MARGIN {
+                document.line(Self::MARGIN, y, width - Self::MARGIN, y, "#dddddd", 1.0);
+            }
+        }
+        document
+    }
+
+    fn render_frame(lines: &[String], state: Option<&RewriteState>) -> SvgDocument {
+        let line_height = Self::FONT_SIZE + 5.0;
+        let n_pairs = state.map_or(0, |s| s.pairs.len());
+        let text_width = lines
+            .iter()
+            .map(|l| Self::text_width(l))
+            .fold(0.0, f64::max);
+        let width = text_width.max(n_pairs as f64 * Self::CELL) + 2.0 * Self::MARGIN;
+        let pairs_height = if state.is_some() {
+            2.0 * Self::CELL + Self::CELL / 2.0
+        } else {
+            0.0
+        };
+        let height = Self::MARGIN + pairs_height + lines.len() as f64 * line_height;
+
+        let mut document = SvgDocument::new(width, height);
+        let mut y = Self::MARGIN;
+        document.text(
+            Self::MARGIN,
+            y,
+            &lines[0],
+            Self::FONT_SIZE,
+            "black",
+            "start",
+        );
+        y += line_height / 2.0;
+        if let Some(state) = state {
+            Self::draw_pairs(&mut document, Self::MARGIN, y, &state.pairs, Some(state));
+            y += pairs_height;
+        }
+        for line in &lines[1..] {
+            y += line_height / 2.0;
+            let fill = if line.starts_with("halted") {
+                "#d62728"
+            } else {
+                "#555555"
+            };
+            document.text(Self::MARGIN, y, line, Self::FONT_SIZE, fill, "start");
+            y += line_height / 2.0;
+        }
+        document
+    }
+
+    fn draw_pairs(
+        document: &mut SvgDocument,
+        x: f64,
+        y: f64,
+        pairs: &[BasePair],
+        state: Option<&RewriteState>,
+    ) {
+        for (pos, pair) in pairs.iter().enumerate() {
+            let cx = x + pos as f64 * Self::CELL;
+            if let Some(state) = state {
+                if pos == state.unit {
+                    let fill = if state.copy_mode {
+                        "#fdd0a2"
+                    } else {
+                        "#fff3b0"
+                    };
+                    document.rect(cx, y, Self::CELL, 2.0 * Self::CELL, fill, "#e6a800");
+                }
+            }
+            if let Some(comp) = pair.comp {
+                document.text(
+                    cx + Self::CELL / 2.0,
+                    y + Self::CELL / 2.0,
+                    Rewriter::get_comp_glyph(comp),
+                    Self::FONT_SIZE + 2.0,
+                    "#1f77b4",
+                    "middle",
+                );
+            }
+            if let Some(bind) = pair.bind {
+                document.text(
+                    cx + Self::CELL / 2.0,
+                    y + 1.5 * Self::CELL,
+                    &bind.to_string(),
+                    Self::FONT_SIZE + 2.0,
+                    "black",
+                    "middle",
+                );
+            }
+        }
+    }
+
+    fn text_width(text: &str) -> f64 {
+        text.chars().count() as f64 * Self::CHAR_WIDTH
+    }
+
+    fn join_strands(strands: &[Strand]) -> String {
+        strands
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
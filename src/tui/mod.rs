@@ -0,0 +1,7 @@
+mod stepper;
+mod stepper_app;
+mod stepper_view;
+
+pub use stepper::Stepper;
+pub use stepper_app::StepperApp;
+pub use stepper_view::StepperView;
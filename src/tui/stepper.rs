@@ -0,0 +1,84 @@
+use crate::typogenetics::{AminoAcid, Enzyme, RewriteState, Rewriter, Strand};
+
+/// Steps an enzyme through a strand one amino acid at a time using `Rewriter::step`,
+/// keeping every intermediate state so that steps can be undone.
+pub struct Stepper {
+    enzyme: Enzyme,
+    strand: Strand,
+    history: Vec<RewriteState>,
+    position: usize,
+}
+
+impl Stepper {
+    pub fn new(enzyme: &Enzyme, strand: &Strand) -> Stepper {
+        Stepper {
+            enzyme: enzyme.clone(),
+            strand: strand.clone(),
+            history: Rewriter::start(enzyme, strand).into_iter().collect(),
+            position: 0,
+        }
+    }
+
+    pub fn get_enzyme(&self) -> &Enzyme {
+        &self.enzyme
+    }
+
+    pub fn get_strand(&self) -> &Strand {
+        &self.strand
+    }
+
+    /// The current state, or `None` if the enzyme does not bind to the strand.
+    pub fn get_state(&self) -> Option<&RewriteState> {
+        self.history.get(self.position)
+    }
+
+    /// The amino acid that will be applied by the next step.
+    pub fn get_next_amino_acid(&self) -> Option<AminoAcid> {
+        let state = self.get_state()?;
+        if state.is_finished(&self.enzyme) {
+            return None;
+        }
+        self.enzyme.iter_amino_acids().nth(state.step).copied()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.get_state()
+            .is_none_or(|state| state.is_finished(&self.enzyme))
+    }
+
+    pub fn step_forward(&mut self) -> bool {
+        if self.position + 1 < self.history.len() {
+            self.position += 1;
+            return true;
+        }
+        if self.is_finished() {
+            return false;
+        }
+
+        let mut state = self.history[self.position].clone();
+        Rewriter::step(&self.enzyme, &mut state);
+        self.history.push(state);
+        self.position += 1;
+        true
+    }
+
+    pub fn step_back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    /// Strands produced by the rewrite so far, or the original strand if unbound.
+    pub fn get_products(&self) -> Vec<Strand> {
+        match self.get_state() {
+            Some(state) => Rewriter::get_products(state),
+            None => vec![self.strand.clone()],
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+
+use crate::tui::{Stepper, StepperView};
+use crate::typogenetics::{Enzyme, Strand};
+
+/// Terminal UI that lets the user step an enzyme through a strand.
+pub struct StepperApp;
+
+impl StepperApp {
+    const TICK: Duration = Duration::from_millis(300);
+
+    pub fn run(enzyme: &Enzyme, strand: &Strand) -> std::io::Result<()> {
+        let mut terminal = ratatui::init();
+        let result = Self::event_loop(&mut terminal, Stepper::new(enzyme, strand));
+        ratatui::restore();
+        result
+    }
+
+    fn event_loop(terminal: &mut DefaultTerminal, mut stepper: Stepper) -> std::io::Result<()> {
+        let mut running = false;
+        let mut last_tick = Instant::now();
+
+        loop {
+            terminal.draw(|frame| StepperView::draw(frame, &stepper, running))?;
+
+            let timeout = Self::TICK.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                            running = false;
+                            stepper.step_forward();
+                        }
+                        KeyCode::Left | KeyCode::Char('h') => {
+                            running = false;
+                            stepper.step_back();
+                        }
+                        KeyCode::Char('r') => running = !running && !stepper.is_finished(),
+                        KeyCode::Char('0') | KeyCode::Home => {
+                            running = false;
+                            stepper.reset();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if last_tick.elapsed() >= Self::TICK {
+                if running && !stepper.step_forward() {
+                    running = false;
+                }
+                last_tick = Instant::now();
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::tui::Stepper;
+use crate::typogenetics::{RewriteState, Rewriter};
+
+/// Draws the current state of a stepper.
+pub struct StepperView;
+
+impl StepperView {
+    pub fn draw(frame: &mut Frame, stepper: &Stepper, running: bool) {
+        let [enzyme_area, strand_area, status_area, products_area, help_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(5),
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(Self::enzyme_widget(stepper), enzyme_area);
+        frame.render_widget(Self::strand_widget(stepper), strand_area);
+        frame.render_widget(Self::status_widget(stepper, running), status_area);
+        frame.render_widget(Self::products_widget(stepper), products_area);
+        frame.render_widget(
+            Paragraph::new("→/l: step  ←/h: back  r: run/pause  0: reset  q: quit")
+                .style(Style::default().fg(Color::DarkGray)),
+            help_area,
+        );
+    }
+
+    fn enzyme_widget(stepper: &Stepper) -> Paragraph<'static> {
+        let state = stepper.get_state();
+        let mut spans = Vec::new();
+        for (i, amino_acid) in stepper.get_enzyme().iter_amino_acids().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("-"));
+            }
+            let style = match state {
+                Some(state) if state.halt.is_some() && i + 1 == state.step => {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                }
+                Some(state) if i < state.step => Style::default().fg(Color::DarkGray),
+                Some(state) if i == state.step && state.halt.is_none() => Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+                _ => Style::default(),
+            };
+            spans.push(Span::styled(amino_acid.to_string(), style));
+        }
+        Paragraph::new(Line::from(spans))
+            .block(Block::default().borders(Borders::ALL).title("Enzyme"))
+    }
+
+    fn strand_widget(stepper: &Stepper) -> Paragraph<'static> {
+        let block = Block::default().borders(Borders::ALL).title("Strand");
+        match stepper.get_state() {
+            Some(state) => Paragraph::new(Self::pairs_lines(state)).block(block),
+            None => Paragraph::new(vec![
+                Line::from(stepper.get_strand().to_string()),
+                Line::from(Span::styled(
+                    "Enzyme has no binding site on this strand",
+                    Style::default().fg(Color::Red),
+                )),
+            ])
+            .block(block),
+        }
+    }
+
+    fn pairs_lines(state: &RewriteState) -> Vec<Line<'static>> {
+        let unit_style = Style::default().add_modifier(Modifier::REVERSED);
+        let mut comp_spans = Vec::new();
+        let mut bind_spans = Vec::new();
+        let mut cursor = String::new();
+
+        for (pos, pair) in state.pairs.iter().enumerate() {
+            let comp = pair.comp.map_or(" ", Rewriter::get_comp_glyph);
+            let bind = pair.bind.map_or(String::from(" "), |b| b.to_string());
+            let comp_style = Style::default().fg(Color::Cyan);
+            if pos == state.unit {
+                comp_spans.push(Span::styled(comp, comp_style.patch(unit_style)));
+                bind_spans.push(Span::styled(bind, unit_style));
+                cursor.push('^');
+            } else {
+                comp_spans.push(Span::styled(comp, comp_style));
+                bind_spans.push(Span::raw(bind));
+                cursor.push(' ');
+            }
+        }
+
+        vec![
+            Line::from(comp_spans),
+            Line::from(bind_spans),
+            Line::from(Span::styled(cursor, Style::default().fg(Color::Yellow))),
+        ]
+    }
+
+    fn status_widget(stepper: &Stepper, running: bool) -> Paragraph<'static> {
+        let status = match stepper.get_state() {
+            None => String::from("unbound"),
+            Some(state) => {
+                let progress = match state.halt {
+                    Some(halt) => format!("halted: {}", halt),
+                    None if stepper.is_finished() => String::from("finished"),
+                    None if running => String::from("running"),
+                    None => String::from("paused"),
+                };
+                format!(
+                    "Step {}/{}  Unit {}  Copy mode {}  {}",
+                    state.step,
+                    stepper.get_enzyme().len(),
+                    state.unit,
+                    if state.copy_mode { "on" } else { "off" },
+                    progress
+                )
+            }
+        };
+        Paragraph::new(status).block(Block::default().borders(Borders::ALL).title("Status"))
+    }
+
+    fn products_widget(stepper: &Stepper) -> Paragraph<'static> {
+        let (title, strands) = match stepper.get_state() {
+            Some(state) if !stepper.is_finished() => {
+                ("Fragments cut so far", state.strands.clone())
+            }
+            _ => ("Products", stepper.get_products()),
+        };
+        let lines: Vec<Line> = strands
+            .iter()
+            .map(|s| Line::from(format!("- {}", s)))
+            .collect();
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title))
+    }
+}
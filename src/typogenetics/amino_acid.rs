@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum AminoAcid {
     Cut,
     Del,
@@ -20,24 +20,49 @@ pub enum AminoAcid {
 }
 
 impl AminoAcid {
+    pub const ALL: [AminoAcid; 15] = [
+        AminoAcid::Cut,
+        AminoAcid::Del,
+        AminoAcid::Swi,
+        AminoAcid::Mvr,
+        AminoAcid::Mvl,
+        AminoAcid::Cop,
+        AminoAcid::Off,
+        AminoAcid::Ina,
+        AminoAcid::Inc,
+        AminoAcid::Ing,
+        AminoAcid::Int,
+        AminoAcid::Rpy,
+        AminoAcid::Rpu,
+        AminoAcid::Lpy,
+        AminoAcid::Lpu,
+    ];
+
     pub fn from_string(amino_acid_str: &str) -> AminoAcid {
+        match Self::try_from_string(amino_acid_str) {
+            Some(amino_acid) => amino_acid,
+            None => panic!("Invalid amino acid string"),
+        }
+    }
+
+    pub fn try_from_string(amino_acid_str: &str) -> Option<AminoAcid> {
         match amino_acid_str {
-            "cut" => AminoAcid::Cut,
-            "del" => AminoAcid::Del,
-            "swi" => AminoAcid::Swi,
-            "mvr" => AminoAcid::Mvr,
-            "mvl" => AminoAcid::Mvl,
-            "cop" => AminoAcid::Cop,
-            "off" => AminoAcid::Off,
-            "ina" => AminoAcid::Ina,
-            "inc" => AminoAcid::Inc,
-            "ing" => AminoAcid::Ing,
-            "int" => AminoAcid::Int,
-            "rpy" => AminoAcid::Rpy,
-            "rpu" => AminoAcid::Rpu,
-            "lpy" => AminoAcid::Lpy,
-            "lpu" => AminoAcid::Lpu,
-            _ => panic!("Invalid amino acid string"),
+            "cut" => Some(AminoAcid::Cut),
+            "del" => Some(AminoAcid::Del),
+            "swi" => Some(AminoAcid::Swi),
+            "mvr" => Some(AminoAcid::Mvr),
+            "mvl" => Some(AminoAcid::Mvl),
+            "cop" => Some(AminoAcid::Cop),
+            "off" => Some(AminoAcid::Off),
+            "ina" => Some(AminoAcid::Ina),
+            "inc" => Some(AminoAcid::Inc),
+            "ing" => Some(AminoAcid::Ing),
+            "int" => Some(AminoAcid::Int),
+            "rpy" => Some(AminoAcid::Rpy),
+            "rpu" => Some(AminoAcid::Rpu),
+            "lpy" => Some(AminoAcid::Lpy),
+            "lpu" => Some(AminoAcid::Lpu),
+            _ => None,
         }
     }
 }
@@ -23,12 +23,19 @@ impl Display for Base {
 
 impl Base {
     pub fn from_string(base_str: &str) -> Base {
+        match Self::try_from_string(base_str) {
+            Some(base) => base,
+            None => panic!("Invalid base string: {}", base_str),
+        }
+    }
+
+    pub fn try_from_string(base_str: &str) -> Option<Base> {
         match base_str {
-            "C" => Base::C,
-            "G" => Base::G,
-            "T" => Base::T,
-            "A" => Base::A,
-            _ => panic!("Invalid base string: {}", base_str),
+            "C" => Some(Base::C),
+            "G" => Some(Base::G),
+            "T" => Some(Base::T),
+            "A" => Some(Base::A),
+            _ => None,
         }
     }
 
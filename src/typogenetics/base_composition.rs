@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::typogenetics::Base;
+
+/// Number of each base in a strand.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct BaseComposition {
+    pub a: usize,
+    pub c: usize,
+    pub g: usize,
+    pub t: usize,
+}
+
+impl BaseComposition {
+    pub fn add(&mut self, base: Base) {
+        match base {
+            Base::A => self.a += 1,
+            Base::C => self.c += 1,
+            Base::G => self.g += 1,
+            Base::T => self.t += 1,
+        }
+    }
+
+    /// Add the counts of another composition, such as that of another strand.
+    pub fn merge(&mut self, other: &BaseComposition) {
+        self.a += other.a;
+        self.c += other.c;
+        self.g += other.g;
+        self.t += other.t;
+    }
+
+    /// The count of each base, in the order A, C, G, T.
+    pub fn get_counts(&self) -> [(Base, usize); 4] {
+        [
+            (Base::A, self.a),
+            (Base::C, self.c),
+            (Base::G, self.g),
+            (Base::T, self.t),
+        ]
+    }
+
+    pub fn get_count(&self, base: Base) -> usize {
+        match base {
+            Base::A => self.a,
+            Base::C => self.c,
+            Base::G => self.g,
+            Base::T => self.t,
+        }
+    }
+
+    pub fn get_total(&self) -> usize {
+        self.a + self.c + self.g + self.t
+    }
+
+    pub fn get_purines(&self) -> usize {
+        self.a + self.g
+    }
+
+    pub fn get_pyrimidines(&self) -> usize {
+        self.c + self.t
+    }
+}
+
+impl Display for BaseComposition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "A {}, C {}, G {}, T {}", self.a, self.c, self.g, self.t)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::typogenetics::Base;
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct BasePair {
     pub bind: Option<Base>,
     pub comp: Option<Base>,
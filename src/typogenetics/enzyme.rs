@@ -5,7 +5,7 @@ use std::{
 
 use crate::typogenetics::AminoAcid;
 
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Enzyme {
     amino_acids: Vec<AminoAcid>,
 }
@@ -22,6 +22,18 @@ impl Enzyme {
         Enzyme { amino_acids }
     }
 
+    pub fn try_from_string(enzyme_str: &str) -> Result<Enzyme, String> {
+        let mut amino_acids = Vec::new();
+        for amino_acid_str in enzyme_str.split('-') {
+            match AminoAcid::try_from_string(amino_acid_str) {
+                Some(amino_acid) => amino_acids.push(amino_acid),
+                None => return Err(format!("Invalid amino acid '{}'", amino_acid_str)),
+            }
+        }
+
+        Ok(Enzyme { amino_acids })
+    }
+
     pub fn iter_amino_acids(&self) -> Iter<'_, AminoAcid> {
         self.amino_acids.iter()
     }
@@ -29,6 +41,10 @@ impl Enzyme {
     pub fn len(&self) -> usize {
         self.amino_acids.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.amino_acids.is_empty()
+    }
 }
 
 impl Display for Enzyme {
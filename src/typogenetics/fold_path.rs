@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use crate::typogenetics::{AminoAcid, Orientation};
+
+/// The lattice coordinates of each amino acid in a folded enzyme. The fold enters the
+/// first amino acid heading right and `orientation` is the heading after the last
+/// amino acid. Coordinates have y pointing down, so a right turn from R heads D.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FoldPath {
+    pub amino_acids: Vec<AminoAcid>,
+    pub points: Vec<(i32, i32)>,
+    pub orientation: Orientation,
+}
+
+impl FoldPath {
+    /// Points visited by more than one amino acid, in the order they were revisited.
+    pub fn get_intersections(&self) -> Vec<(i32, i32)> {
+        let mut seen = HashSet::new();
+        let mut intersections = Vec::new();
+        for point in &self.points {
+            if !seen.insert(*point) && !intersections.contains(point) {
+                intersections.push(*point);
+            }
+        }
+        intersections
+    }
+
+    pub fn is_self_intersecting(&self) -> bool {
+        let unique: HashSet<&(i32, i32)> = self.points.iter().collect();
+        unique.len() < self.points.len()
+    }
+
+    /// The smallest and largest coordinates of any amino acid.
+    pub fn get_bounding_box(&self) -> ((i32, i32), (i32, i32)) {
+        let min_x = self.points.iter().map(|p| p.0).min().unwrap_or(0);
+        let min_y = self.points.iter().map(|p| p.1).min().unwrap_or(0);
+        let max_x = self.points.iter().map(|p| p.0).max().unwrap_or(0);
+        let max_y = self.points.iter().map(|p| p.1).max().unwrap_or(0);
+        ((min_x, min_y), (max_x, max_y))
+    }
+
+    /// The vector from the first amino acid to the last.
+    pub fn get_end_to_end(&self) -> (i32, i32) {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (last.0 - first.0, last.1 - first.1),
+            _ => (0, 0),
+        }
+    }
+
+    /// Draw the fold with each amino acid in a three character cell. The cell past the
+    /// last amino acid shows the final heading and overlapping amino acids show `###`.
+    pub fn to_ascii(&self) -> String {
+        let last = self.points.last().copied().unwrap_or((0, 0));
+        let (dx, dy) = self.orientation.to_vector();
+        let head = (last.0 + dx, last.1 + dy);
+        let head_str = match self.orientation {
+            Orientation::R => ">",
+            Orientation::D => " v",
+            Orientation::L => "  <",
+            Orientation::U => " ^",
+        };
+
+        let ((min_x, min_y), (max_x, max_y)) = self.get_bounding_box();
+        let min_x = min_x.min(head.0);
+        let min_y = min_y.min(head.1);
+        let max_x = max_x.max(head.0);
+        let max_y = max_y.max(head.1);
+
+        let n_cols = ((max_x - min_x + 1) * 4) as usize;
+        let n_rows = ((max_y - min_y + 1) * 2) as usize;
+        let mut grid = vec![vec![' '; n_cols]; n_rows];
+        let to_grid = |p: (i32, i32)| (((p.0 - min_x) * 4) as usize, ((p.1 - min_y) * 2) as usize);
+
+        let mut path: Vec<(i32, i32)> = self.points.clone();
+        path.push(head);
+        for pair in path.windows(2) {
+            let (c1, r1) = to_grid(pair[0]);
+            let (c2, r2) = to_grid(pair[1]);
+            if r1 == r2 {
+                grid[r1][c1.min(c2) + 3] = '-';
+            } else {
+                grid[r1.min(r2) + 1][c1 + 1] = '|';
+            }
+        }
+
+        let mut cells: Vec<((i32, i32), String)> = Vec::new();
+        let intersections = self.get_intersections();
+        for (point, amino_acid) in self.points.iter().zip(&self.amino_acids) {
+            let label = if intersections.contains(point) {
+                String::from("###")
+            } else {
+                amino_acid.to_string()
+            };
+            cells.push((*point, label));
+        }
+        if !self.points.contains(&head) {
+            cells.push((head, String::from(head_str)));
+        }
+        for (point, label) in cells {
+            let (col, row) = to_grid(point);
+            for (i, c) in label.chars().take(3).enumerate() {
+                grid[row][col + i] = c;
+            }
+        }
+
+        grid.iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .filter(|row| !row.is_empty())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
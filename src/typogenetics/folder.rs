@@ -1,4 +1,4 @@
-use crate::typogenetics::{AminoAcid, Base, Enzyme, Orientation, Strand, Turn};
+use crate::typogenetics::{AminoAcid, Base, Enzyme, FoldPath, Orientation, Strand, Turn};
 
 /// | ins | dir |
 /// | --- | --- |
@@ -36,6 +36,23 @@ impl Folder {
         Orientation::from_turning_number(turning_number)
     }
 
+    pub fn fold_path(enzyme: &Enzyme) -> FoldPath {
+        let mut points = Vec::new();
+        let mut point = (0, 0);
+        let mut turning_number = 0;
+        for amino_acid in enzyme.iter_amino_acids() {
+            points.push(point);
+            turning_number += Self::get_turn(amino_acid).to_int();
+            let (dx, dy) = Orientation::from_turning_number(turning_number).to_vector();
+            point = (point.0 + dx, point.1 + dy);
+        }
+        FoldPath {
+            amino_acids: enzyme.iter_amino_acids().cloned().collect(),
+            points,
+            orientation: Orientation::from_turning_number(turning_number),
+        }
+    }
+
     pub fn get_turn(amino_acid: &AminoAcid) -> Turn {
         match amino_acid {
             AminoAcid::Cut => Turn::S,
@@ -0,0 +1,18 @@
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HaltReason {
+    EndOfStrand,
+    EmptyComplement,
+}
+
+impl Display for HaltReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            HaltReason::EndOfStrand => write!(f, "Reached end of strand"),
+            HaltReason::EmptyComplement => {
+                write!(f, "Tried to switch to empty base pair complement")
+            }
+        }
+    }
+}
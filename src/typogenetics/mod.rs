@@ -1,11 +1,17 @@
 mod amino_acid;
 mod base;
+mod base_composition;
 mod base_pair;
 mod base_type;
 mod duplet;
 mod enzyme;
+mod fold_path;
 mod folder;
+mod halt_reason;
+mod motif;
+mod motif_element;
 mod orientation;
+mod rewrite_state;
 mod rewriter;
 mod strand;
 mod translator;
@@ -13,12 +19,18 @@ mod turn;
 
 pub use amino_acid::AminoAcid;
 pub use base::Base;
+pub use base_composition::BaseComposition;
 pub use base_pair::BasePair;
 pub use base_type::BaseType;
 pub use duplet::Duplet;
 pub use enzyme::Enzyme;
+pub use fold_path::FoldPath;
 pub use folder::Folder;
+pub use halt_reason::HaltReason;
+pub use motif::Motif;
+pub use motif_element::MotifElement;
 pub use orientation::Orientation;
+pub use rewrite_state::RewriteState;
 pub use rewriter::Rewriter;
 pub use strand::Strand;
 pub use translator::Translator;
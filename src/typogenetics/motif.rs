@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+
+use crate::typogenetics::{MotifElement, Strand};
+
+/// A pattern of bases to search strands for, where `R` matches a purine, `Y` a
+/// pyrimidine and `N` any base, so "TRY" matches TAC, TAT, TGC and TGT.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Motif {
+    elements: Vec<MotifElement>,
+}
+
+impl Motif {
+    pub fn new(elements: Vec<MotifElement>) -> Motif {
+        Motif { elements }
+    }
+
+    pub fn try_from_string(motif_str: &str) -> Result<Motif, String> {
+        let mut elements = Vec::new();
+        for (pos, c) in motif_str.chars().filter(|&c| c != ' ').enumerate() {
+            match MotifElement::try_from_char(c) {
+                Some(element) => elements.push(element),
+                None => {
+                    return Err(format!(
+                        "Invalid motif element '{}' at position {}, expected A, C, G, T, R, Y or N",
+                        c,
+                        pos + 1
+                    ))
+                }
+            }
+        }
+        if elements.is_empty() {
+            return Err(String::from("Motif must contain at least one element"));
+        }
+
+        Ok(Motif { elements })
+    }
+
+    pub fn iter_elements(&self) -> impl Iterator<Item = &MotifElement> {
+        self.elements.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Whether the motif matches the strand starting at a position.
+    pub fn matches_at(&self, strand: &Strand, pos: usize) -> bool {
+        pos + self.len() <= strand.len()
+            && self
+                .elements
+                .iter()
+                .zip(strand.iter_bases().skip(pos))
+                .all(|(element, &base)| element.matches(base))
+    }
+}
+
+impl Display for Motif {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for element in &self.elements {
+            write!(f, "{}", element)?;
+        }
+        Ok(())
+    }
+}
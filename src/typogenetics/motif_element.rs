@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::typogenetics::{Base, BaseType};
+
+/// One position of a `Motif`: a base, any base of a type, or any base at all.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MotifElement {
+    Base(Base),
+    Type(BaseType),
+    Any,
+}
+
+impl MotifElement {
+    /// Parse a base, `R` for a purine, `Y` for a pyrimidine or `N` for any base.
+    pub fn try_from_char(c: char) -> Option<MotifElement> {
+        match c {
+            'R' => Some(MotifElement::Type(BaseType::Purine)),
+            'Y' => Some(MotifElement::Type(BaseType::Pyrimidine)),
+            'N' => Some(MotifElement::Any),
+            _ => Base::try_from_string(&c.to_string()).map(MotifElement::Base),
+        }
+    }
+
+    pub fn matches(&self, base: Base) -> bool {
+        match self {
+            MotifElement::Base(element_base) => *element_base == base,
+            MotifElement::Type(base_type) => base.is_type(*base_type),
+            MotifElement::Any => true,
+        }
+    }
+}
+
+impl Display for MotifElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MotifElement::Base(base) => write!(f, "{}", base),
+            MotifElement::Type(BaseType::Purine) => write!(f, "R"),
+            MotifElement::Type(BaseType::Pyrimidine) => write!(f, "Y"),
+            MotifElement::Any => write!(f, "N"),
+        }
+    }
+}
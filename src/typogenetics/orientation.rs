@@ -7,6 +7,13 @@ pub enum Orientation {
 }
 
 impl Orientation {
+    pub const ALL: [Orientation; 4] = [
+        Orientation::R,
+        Orientation::D,
+        Orientation::L,
+        Orientation::U,
+    ];
+
     pub fn from_turning_number(turning_number: i32) -> Orientation {
         match turning_number.rem_euclid(4) {
             0 => Orientation::R,
@@ -16,4 +23,14 @@ impl Orientation {
             _ => panic!("Invalid turning number: {}", turning_number),
         }
     }
+
+    /// Unit vector for the orientation on the folding lattice, where y points down.
+    pub fn to_vector(self) -> (i32, i32) {
+        match self {
+            Orientation::R => (1, 0),
+            Orientation::D => (0, 1),
+            Orientation::L => (-1, 0),
+            Orientation::U => (0, -1),
+        }
+    }
 }
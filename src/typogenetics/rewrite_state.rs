@@ -0,0 +1,19 @@
+use crate::typogenetics::{BasePair, Enzyme, HaltReason, Strand};
+
+/// The state of an enzyme partway through rewriting a strand. `step` is the index of
+/// the next amino acid to apply and `strands` holds the pieces cut off so far.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RewriteState {
+    pub pairs: Vec<BasePair>,
+    pub unit: usize,
+    pub copy_mode: bool,
+    pub step: usize,
+    pub strands: Vec<Strand>,
+    pub halt: Option<HaltReason>,
+}
+
+impl RewriteState {
+    pub fn is_finished(&self, enzyme: &Enzyme) -> bool {
+        self.halt.is_some() || self.step >= enzyme.len()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::typogenetics::{AminoAcid, Base, BasePair, BaseType, Enzyme, Folder, Strand};
+use crate::typogenetics::{
+    AminoAcid, Base, BasePair, BaseType, Enzyme, Folder, HaltReason, RewriteState, Strand,
+};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 
@@ -23,165 +25,200 @@ pub struct Rewriter;
 
 impl Rewriter {
     pub fn rewrite(enzyme: &Enzyme, strand: &Strand) -> Vec<Strand> {
-        let mut copy_mode = false;
-        let unit = Folder::get_binding_site(enzyme, strand);
-
-        if let Some(mut unit) = unit {
-            let mut pairs: Vec<BasePair> = strand
-                .iter_bases()
-                .map(|&base| BasePair {
-                    bind: Some(base),
-                    comp: None,
-                })
-                .collect();
-
-            log::debug!("{}", Self::pairs_to_string(&pairs, unit));
-
-            let mut strands = Vec::new();
-
-            for amino_acid in enzyme.iter_amino_acids() {
-                log::debug!(
-                    "Applying {:?} with unit = {}, copy = {}",
-                    amino_acid,
-                    unit,
-                    copy_mode
-                );
+        if let Some(mut state) = Self::start(enzyme, strand) {
+            while !state.is_finished(enzyme) {
+                Self::step(enzyme, &mut state);
+            }
+            Self::get_products(&state)
+        } else {
+            vec![strand.clone()]
+        }
+    }
 
-                if *amino_acid == AminoAcid::Cut {
-                    let cut_pairs = pairs.split_off(unit + 1);
-                    strands.extend(Self::strands_from_pairs(&cut_pairs));
-                    pairs.truncate(unit + 1);
-                } else if *amino_acid == AminoAcid::Del {
-                    if let Some(pair) = pairs.get_mut(unit) {
-                        pair.bind = None;
-                    }
-                    if unit == 0 {
-                        log::debug!("Reached end of strand");
-                        break;
-                    }
-                    unit -= 1;
+    /// Bind an enzyme to a strand, returning `None` if the enzyme has nowhere to bind.
+    pub fn start(enzyme: &Enzyme, strand: &Strand) -> Option<RewriteState> {
+        let unit = Folder::get_binding_site(enzyme, strand)?;
+        let pairs: Vec<BasePair> = strand
+            .iter_bases()
+            .map(|&base| BasePair {
+                bind: Some(base),
+                comp: None,
+            })
+            .collect();
+
+        log::debug!("{}", Self::pairs_to_string(&pairs, unit));
+
+        Some(RewriteState {
+            pairs,
+            unit,
+            copy_mode: false,
+            step: 0,
+            strands: Vec::new(),
+            halt: None,
+        })
+    }
 
-                    if pairs.get(unit).map_or(true, |pair| pair.bind.is_none()) {
-                        log::debug!("Reached end of strand");
-                        break;
-                    }
-                } else if *amino_acid == AminoAcid::Swi {
-                    if pairs.get(unit).map_or(true, |pair| pair.comp.is_none()) {
-                        log::debug!("Tried to switch to empty base pair complement");
-                        break;
-                    }
-                    for pair in &mut pairs {
-                        pair.swap();
-                    }
-                    pairs.reverse();
-                    unit = pairs.len() - unit - 1;
-                } else if *amino_acid == AminoAcid::Mvr || *amino_acid == AminoAcid::Mvl {
-                    if let Some(direction) = Self::amino_acid_to_direction(*amino_acid) {
-                        let new_unit = Self::usize_add(unit, direction);
-                        if let Some(new_unit) = new_unit {
-                            unit = new_unit;
-                        } else {
-                            log::debug!("Reached end of strand");
-                            break;
-                        }
+    /// Apply the next amino acid of the enzyme to the rewrite state.
+    pub fn step(enzyme: &Enzyme, state: &mut RewriteState) {
+        let amino_acid = match enzyme.iter_amino_acids().nth(state.step) {
+            Some(amino_acid) if state.halt.is_none() => amino_acid,
+            _ => return,
+        };
+        state.step += 1;
+
+        log::debug!(
+            "Applying {:?} with unit = {}, copy = {}",
+            amino_acid,
+            state.unit,
+            state.copy_mode
+        );
+
+        if let Some(halt) = Self::apply(*amino_acid, state) {
+            log::debug!("{}", halt);
+            state.halt = Some(halt);
+            return;
+        }
 
-                        if unit >= pairs.len()
-                            || pairs.get(unit).map_or(true, |pair| pair.bind.is_none())
-                        {
-                            log::debug!("Reached end of strand");
-                            break;
-                        }
+        // Debug print for pairs
+        log::debug!("{}", Self::pairs_to_string(&state.pairs, state.unit));
+    }
 
-                        if copy_mode {
-                            if let Some(pair) = pairs.get_mut(unit) {
-                                pair.add_comp()
-                            }
-                        }
+    /// Every state the rewrite passes through, starting with the enzyme freshly bound.
+    pub fn trace(enzyme: &Enzyme, strand: &Strand) -> Vec<RewriteState> {
+        let mut states = Vec::new();
+        if let Some(mut state) = Self::start(enzyme, strand) {
+            states.push(state.clone());
+            while !state.is_finished(enzyme) {
+                Self::step(enzyme, &mut state);
+                states.push(state.clone());
+            }
+        }
+        states
+    }
+
+    /// All strands produced so far, including those cut off the working strand.
+    pub fn get_products(state: &RewriteState) -> Vec<Strand> {
+        let mut strands = state.strands.clone();
+        strands.extend(Self::strands_from_pairs(&state.pairs));
+        strands
+    }
+
+    #[allow(clippy::unnecessary_map_or)]
+    fn apply(amino_acid: AminoAcid, state: &mut RewriteState) -> Option<HaltReason> {
+        let pairs = &mut state.pairs;
+        let unit = &mut state.unit;
+
+        if amino_acid == AminoAcid::Cut {
+            let cut_pairs = pairs.split_off(*unit + 1);
+            state.strands.extend(Self::strands_from_pairs(&cut_pairs));
+            pairs.truncate(*unit + 1);
+        } else if amino_acid == AminoAcid::Del {
+            if let Some(pair) = pairs.get_mut(*unit) {
+                pair.bind = None;
+            }
+            if *unit == 0 {
+                return Some(HaltReason::EndOfStrand);
+            }
+            *unit -= 1;
+
+            if pairs.get(*unit).map_or(true, |pair| pair.bind.is_none()) {
+                return Some(HaltReason::EndOfStrand);
+            }
+        } else if amino_acid == AminoAcid::Swi {
+            if pairs.get(*unit).map_or(true, |pair| pair.comp.is_none()) {
+                return Some(HaltReason::EmptyComplement);
+            }
+            for pair in pairs.iter_mut() {
+                pair.swap();
+            }
+            pairs.reverse();
+            *unit = pairs.len() - *unit - 1;
+        } else if amino_acid == AminoAcid::Mvr || amino_acid == AminoAcid::Mvl {
+            if let Some(direction) = Self::amino_acid_to_direction(amino_acid) {
+                let new_unit = Self::usize_add(*unit, direction);
+                if let Some(new_unit) = new_unit {
+                    *unit = new_unit;
+                } else {
+                    return Some(HaltReason::EndOfStrand);
+                }
+
+                if *unit >= pairs.len() || pairs.get(*unit).map_or(true, |pair| pair.bind.is_none())
+                {
+                    return Some(HaltReason::EndOfStrand);
+                }
+
+                if state.copy_mode {
+                    if let Some(pair) = pairs.get_mut(*unit) {
+                        pair.add_comp()
                     }
-                } else if *amino_acid == AminoAcid::Cop {
-                    copy_mode = true;
-                    if let Some(pair) = pairs.get_mut(unit) {
-                        if let Some(bind) = pair.bind {
-                            pair.comp = Some(bind.get_complement());
-                        }
+                }
+            }
+        } else if amino_acid == AminoAcid::Cop {
+            state.copy_mode = true;
+            if let Some(pair) = pairs.get_mut(*unit) {
+                if let Some(bind) = pair.bind {
+                    pair.comp = Some(bind.get_complement());
+                }
+            }
+        } else if amino_acid == AminoAcid::Off {
+            state.copy_mode = false;
+        } else if amino_acid == AminoAcid::Ina
+            || amino_acid == AminoAcid::Inc
+            || amino_acid == AminoAcid::Ing
+            || amino_acid == AminoAcid::Int
+        {
+            if let Some(bind) = Self::amino_acid_to_base(amino_acid) {
+                pairs.insert(
+                    *unit + 1,
+                    BasePair {
+                        bind: Some(bind),
+                        comp: if state.copy_mode {
+                            Some(bind.get_complement())
+                        } else {
+                            None
+                        },
+                    },
+                );
+            }
+        } else if amino_acid == AminoAcid::Rpy
+            || amino_acid == AminoAcid::Rpu
+            || amino_acid == AminoAcid::Lpy
+            || amino_acid == AminoAcid::Lpu
+        {
+            if let Some(direction) = Self::amino_acid_to_direction(amino_acid) {
+                loop {
+                    let new_unit = Self::usize_add(*unit, direction);
+                    if let Some(new_unit) = new_unit {
+                        *unit = new_unit;
+                    } else {
+                        return Some(HaltReason::EndOfStrand);
                     }
-                } else if *amino_acid == AminoAcid::Off {
-                    copy_mode = false;
-                } else if *amino_acid == AminoAcid::Ina
-                    || *amino_acid == AminoAcid::Inc
-                    || *amino_acid == AminoAcid::Ing
-                    || *amino_acid == AminoAcid::Int
-                {
-                    if let Some(bind) = Self::amino_acid_to_base(*amino_acid) {
-                        pairs.insert(
-                            unit + 1,
-                            BasePair {
-                                bind: Some(bind),
-                                comp: if copy_mode {
-                                    Some(bind.get_complement())
-                                } else {
-                                    None
-                                },
-                            },
-                        );
+
+                    if *unit >= pairs.len() {
+                        return Some(HaltReason::EndOfStrand);
                     }
-                } else if *amino_acid == AminoAcid::Rpy
-                    || *amino_acid == AminoAcid::Rpu
-                    || *amino_acid == AminoAcid::Lpy
-                    || *amino_acid == AminoAcid::Lpu
-                {
-                    if let Some(direction) = Self::amino_acid_to_direction(*amino_acid) {
-                        let mut end_of_strand = false;
-
-                        while !end_of_strand {
-                            let new_unit = Self::usize_add(unit, direction);
-                            if let Some(new_unit) = new_unit {
-                                unit = new_unit;
-                            } else {
-                                end_of_strand = true;
-                                break;
-                            }
 
-                            if unit >= pairs.len() {
-                                end_of_strand = true;
-                                break;
-                            } else {
-                                let pair = &pairs[unit];
-                                let bind_base = pair.bind;
-                                if let Some(bind_base) = bind_base {
-                                    if copy_mode {
-                                        if let Some(pair) = pairs.get_mut(unit) {
-                                            pair.add_comp();
-                                        }
-                                    }
-
-                                    let base_type = Self::amino_acid_to_base_type(*amino_acid);
-                                    if bind_base.is_type(base_type) {
-                                        break;
-                                    }
-                                } else {
-                                    end_of_strand = true;
-                                    break;
-                                }
+                    let pair = &pairs[*unit];
+                    let bind_base = pair.bind;
+                    if let Some(bind_base) = bind_base {
+                        if state.copy_mode {
+                            if let Some(pair) = pairs.get_mut(*unit) {
+                                pair.add_comp();
                             }
                         }
 
-                        if end_of_strand {
-                            log::debug!("Reached end of strand");
+                        let base_type = Self::amino_acid_to_base_type(amino_acid);
+                        if bind_base.is_type(base_type) {
                             break;
                         }
+                    } else {
+                        return Some(HaltReason::EndOfStrand);
                     }
                 }
-
-                // Debug print for pairs
-                log::debug!("{}", Self::pairs_to_string(&pairs, unit));
             }
-
-            strands.extend(Self::strands_from_pairs(&pairs));
-            strands
-        } else {
-            vec![strand.clone()]
         }
+
+        None
     }
 
     fn usize_add(u: usize, i: i32) -> Option<usize> {
@@ -224,7 +261,7 @@ impl Rewriter {
         strands
     }
 
-    fn amino_acid_to_base(amino_acid: AminoAcid) -> Option<Base> {
+    pub(crate) fn amino_acid_to_base(amino_acid: AminoAcid) -> Option<Base> {
         match amino_acid {
             AminoAcid::Ina => Some(Base::A),
             AminoAcid::Inc => Some(Base::C),
@@ -234,15 +271,15 @@ impl Rewriter {
         }
     }
 
-    fn amino_acid_to_base_type(amino_acid: AminoAcid) -> BaseType {
+    pub(crate) fn amino_acid_to_base_type(amino_acid: AminoAcid) -> BaseType {
         match amino_acid {
-            AminoAcid::Rpy | AminoAcid::Rpu => BaseType::Pyrimidine,
-            AminoAcid::Lpy | AminoAcid::Lpu => BaseType::Purine,
+            AminoAcid::Rpy | AminoAcid::Lpy => BaseType::Pyrimidine,
+            AminoAcid::Rpu | AminoAcid::Lpu => BaseType::Purine,
             _ => panic!("Invalid amino acid"),
         }
     }
 
-    fn amino_acid_to_direction(amino_acid: AminoAcid) -> Option<i32> {
+    pub(crate) fn amino_acid_to_direction(amino_acid: AminoAcid) -> Option<i32> {
         match amino_acid {
             AminoAcid::Rpy | AminoAcid::Rpu | AminoAcid::Mvr => Some(1),
             AminoAcid::Lpy | AminoAcid::Lpu | AminoAcid::Mvl => Some(-1),
@@ -250,21 +287,22 @@ impl Rewriter {
         }
     }
 
+    /// Upside down glyph used to draw a base on the complementary strand.
+    pub fn get_comp_glyph(base: Base) -> &'static str {
+        match base {
+            Base::A => "∀",
+            Base::C => "Ↄ",
+            Base::G => "⅁",
+            Base::T => "⊥",
+        }
+    }
+
     fn pairs_to_string(pairs: &[BasePair], unit: usize) -> String {
         let mut res = String::from("|");
-        let comp_map: std::collections::HashMap<Base, &str> = [
-            (Base::A, "∀"),
-            (Base::C, "Ↄ"),
-            (Base::G, "⅁"),
-            (Base::T, "⊥"),
-        ]
-        .iter()
-        .cloned()
-        .collect();
 
         for pair in pairs {
             if let Some(comp) = pair.comp {
-                res.push_str(comp_map.get(&comp).unwrap());
+                res.push_str(Self::get_comp_glyph(comp));
             } else {
                 res.push(' ');
             }
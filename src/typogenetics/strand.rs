@@ -1,11 +1,12 @@
 use std::{
     fmt::{Debug, Display, Formatter},
+    ops::{Bound, RangeBounds},
     slice::Iter,
 };
 
-use crate::typogenetics::{Base, Duplet};
+use crate::typogenetics::{Base, BaseComposition, BaseType, Duplet, Motif};
 
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Strand {
     bases: Vec<Base>,
 }
@@ -25,6 +26,21 @@ impl Strand {
         Strand { bases }
     }
 
+    pub fn try_from_string(strand_str: &str) -> Result<Strand, String> {
+        let mut bases = Vec::new();
+        for (pos, c) in strand_str.chars().filter(|&c| c != ' ').enumerate() {
+            match Base::try_from_string(&c.to_string()) {
+                Some(base) => bases.push(base),
+                None => return Err(format!("Invalid base '{}' at position {}", c, pos + 1)),
+            }
+        }
+        if bases.is_empty() {
+            return Err(String::from("Strand must contain at least one base"));
+        }
+
+        Ok(Strand { bases })
+    }
+
     pub fn iter_bases(&self) -> Iter<'_, Base> {
         self.bases.iter()
     }
@@ -46,6 +62,87 @@ impl Strand {
     pub fn len(&self) -> usize {
         self.bases.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    /// The strand with every base replaced by its complement, in the same order.
+    pub fn get_complement(&self) -> Strand {
+        Strand::new(self.bases.iter().map(|b| b.get_complement()).collect())
+    }
+
+    pub fn get_reverse(&self) -> Strand {
+        Strand::new(self.bases.iter().rev().cloned().collect())
+    }
+
+    /// The strand that pairs with this one, read in its own direction. This is how a
+    /// complement copied during a rewrite comes out.
+    pub fn get_reverse_complement(&self) -> Strand {
+        Strand::new(
+            self.bases
+                .iter()
+                .rev()
+                .map(|b| b.get_complement())
+                .collect(),
+        )
+    }
+
+    pub fn concat(&self, other: &Strand) -> Strand {
+        let mut bases = self.bases.clone();
+        bases.extend_from_slice(&other.bases);
+        Strand::new(bases)
+    }
+
+    /// The bases in a range of positions, or `None` if the range is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<Strand> {
+        self.bases
+            .get(Self::to_bounds(&range))
+            .map(|bases| Strand::new(bases.to_vec()))
+    }
+
+    /// The strand with a range of positions replaced by another strand, or `None` if the
+    /// range is out of bounds.
+    pub fn splice(&self, range: impl RangeBounds<usize>, insert: &Strand) -> Option<Strand> {
+        let bounds = Self::to_bounds(&range);
+        self.bases.get(bounds)?;
+        let mut bases = self.bases.clone();
+        bases.splice(bounds, insert.bases.iter().cloned());
+        Some(Strand::new(bases))
+    }
+
+    pub fn get_composition(&self) -> BaseComposition {
+        let mut composition = BaseComposition::default();
+        for &base in &self.bases {
+            composition.add(base);
+        }
+        composition
+    }
+
+    /// Whether each base is a purine or a pyrimidine, the classes that rpy, rpu, lpy and
+    /// lpu search for.
+    pub fn iter_base_types(&self) -> impl Iterator<Item = BaseType> + '_ {
+        self.bases.iter().map(|base| {
+            if base.is_purine() {
+                BaseType::Purine
+            } else {
+                BaseType::Pyrimidine
+            }
+        })
+    }
+
+    /// Start positions of every match of the motif, including overlapping matches.
+    pub fn find_motif<'a>(&'a self, motif: &'a Motif) -> impl Iterator<Item = usize> + 'a {
+        (0..self.len()).filter(move |&pos| motif.matches_at(self, pos))
+    }
+
+    pub fn contains_motif(&self, motif: &Motif) -> bool {
+        self.find_motif(motif).next().is_some()
+    }
+
+    fn to_bounds(range: &impl RangeBounds<usize>) -> (Bound<usize>, Bound<usize>) {
+        (range.start_bound().cloned(), range.end_bound().cloned())
+    }
 }
 
 impl Display for Strand {